@@ -1,97 +1,58 @@
-use crate::Error;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
 
-pub fn run(input: String, day: u8, part: u8) -> Result<String, Error> {
-    let result: Result<String, Error> = match (day, part) {
-        (1, 1) => day01::part1::process(input),
-        (1, 2) => day01::part2::process(input),
-        (2, 1) => day02::part1::process(input),
-        (2, 2) => day02::part2::process(input),
-        (3, 1) => day03::part1::process(input),
-        (3, 2) => day03::part2::process(input),
-        (4, 1) => day04::part1::process(input),
-        (4, 2) => day04::part2::process(input),
-        (5, 1) => day05::part1::process(input),
-        (5, 2) => day05::part2::process(input),
-        (6, 1) => day06::part1::process(input),
-        (6, 2) => day06::part2::process(input),
-        (7, 1) => day07::part1::process(input),
-        (7, 2) => day07::part2::process(input),
-        (8, 1) => day08::part1::process(input),
-        (8, 2) => day08::part2::process(input),
-        (9, 1) => day09::part1::process(input),
-        (9, 2) => day09::part2::process(input),
-        (10, 1) => day10::part1::process(input),
-        (10, 2) => day10::part2::process(input),
-        (11, 1) => day11::part1::process(input),
-        (11, 2) => day11::part2::process(input),
-        (12, 1) => day12::part1::process(input),
-        (day, _) => Err(Error::InvalidDay(day)),
-    };
+#[cfg(test)]
+mod tests {
+    use crate::YEAR;
+    use common::answers::ExampleAnswers;
+    use common::errors::Error;
+    use common::io;
 
-    let output = result?;
+    #[test]
+    fn test_registry() {
+        let days = YEAR
+            .days()
+            .iter()
+            .map(|entry| entry.metadata.day)
+            .collect::<Vec<u8>>();
 
-    let header = format!("--- Day {}. Part {} ---", day, part);
-    let formatted_output = format!("{}\n\n{}", header, output);
+        assert_eq!(days, YEAR.calendar.collect::<Vec<u8>>());
+    }
 
-    Ok(formatted_output)
-}
+    #[test]
+    fn test_missing_part() {
+        let result = YEAR.find(12, 2).unwrap().run(String::new(), 2, None);
 
-pub mod day01 {
-    pub mod errors;
-    pub mod part1; // 01.12.2025
-    pub mod part2; // 01.12.2025
-}
-pub mod day02 {
-    pub mod errors;
-    pub mod part1; // 02.12.2025
-    pub mod part2; // 02.12.2025
-}
-pub mod day03 {
-    pub mod errors;
-    pub mod part1; // 03.12.2025
-    pub mod part2; // 03.12.2025
-}
-pub mod day04 {
-    pub mod errors;
-    pub mod part1; // 04.12.2025
-    pub mod part2; // 04.12.2025
-}
-pub mod day05 {
-    pub mod errors;
-    pub mod part1; // 05.12.2025
-    pub mod part2; // 05.12.2025
-}
-pub mod day06 {
-    pub mod errors;
-    pub mod part1; // 06.12.2025
-    pub mod part2; // 06.12.2025
-}
-pub mod day07 {
-    pub mod errors;
-    pub mod part1; // 07.12.2025
-    pub mod part2; // 07.12.2025
-}
-pub mod day08 {
-    pub mod errors;
-    pub mod part1; // 08.12.2025
-    pub mod part2; // 08.12.2025
-}
-pub mod day09 {
-    pub mod errors;
-    pub mod part1; // 09.12.2025
-    pub mod part2; // 09.12.2025
-}
-pub mod day10 {
-    pub mod errors;
-    pub mod part1; // 10.12.2025
-    pub mod part2; // 10.12.2025
-}
-pub mod day11 {
-    pub mod errors;
-    pub mod part1; // 11.12.2025
-    pub mod part2; // 11.12.2025
-}
-pub mod day12 {
-    pub mod errors;
-    pub mod part1; // 12.12.2025
+        assert!(matches!(result, Err(Error::NotImplemented(12, 2))));
+    }
+
+    #[test]
+    fn test_examples() {
+        let answers = ExampleAnswers::load(&YEAR).unwrap();
+
+        for ((day, example, part), expected) in answers.iter() {
+            let input = io::read_example_file(&YEAR, day, example).unwrap();
+            let actual = YEAR
+                .find(day, part)
+                .unwrap()
+                .run(input, part, None)
+                .unwrap();
+
+            assert_eq!(
+                actual, expected,
+                "Day {}. Example {}. Part {}",
+                day, example, part
+            );
+        }
+    }
 }
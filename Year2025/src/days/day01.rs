@@ -0,0 +1,39 @@
+use crate::YEAR;
+use common::answer::Answer;
+use common::errors::Error;
+use common::registry::Day;
+use common::solution::{Metadata, Solution};
+
+pub mod dial;
+pub mod errors;
+pub mod model;
+pub mod part1; // 01.12.2025
+pub mod part2; // 01.12.2025
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Model = Vec<model::Rotation>;
+
+    const METADATA: Metadata = Metadata {
+        year: YEAR.number,
+        day: 1,
+        title: "Secret Entrance",
+        date: "01.12.2025",
+        parts: 2,
+    };
+
+    fn parse(input: String) -> Result<Self::Model, Error> {
+        Ok(model::parse(&input)?)
+    }
+
+    fn part1(rotations: &Self::Model) -> Result<Answer, Error> {
+        part1::process(rotations)
+    }
+
+    fn part2(rotations: &Self::Model) -> Result<Answer, Error> {
+        part2::process(rotations)
+    }
+}
+
+inventory::submit! { Day::new::<Day01>() }
@@ -0,0 +1,171 @@
+use crate::days::day01::model::{Direction, Rotation};
+
+/// Dial with the positions `0..modulus`, turned one click at a time by rotations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dial {
+    modulus: u64,
+    position: u64,
+}
+
+/// One rotation of the dial, for tracing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Turn {
+    pub from: u64,
+    pub rotation: Rotation,
+    pub to: u64,
+    modulus: u64,
+}
+
+impl Dial {
+    /// Panics if `modulus` is zero.
+    pub fn new(modulus: u64, start: u64) -> Self {
+        assert!(modulus > 0, "Dial needs at least one position");
+
+        Self {
+            modulus,
+            position: start % modulus,
+        }
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    pub fn rotate(&mut self, rotation: Rotation) -> Turn {
+        let from = self.position;
+        let clicks = rotation.step % self.modulus;
+        self.position = match rotation.direction {
+            Direction::Left => sub_mod(from, clicks, self.modulus),
+            Direction::Right => {
+                sub_mod(from, (self.modulus - clicks) % self.modulus, self.modulus)
+            },
+        };
+
+        Turn {
+            from,
+            rotation,
+            to: self.position,
+            modulus: self.modulus,
+        }
+    }
+
+    /// Turns of the dial, one per rotation.
+    pub fn trace<'a>(
+        mut self, rotations: impl IntoIterator<Item = &'a Rotation>,
+    ) -> impl Iterator<Item = Turn> {
+        rotations
+            .into_iter()
+            .map(move |&rotation| self.rotate(rotation))
+    }
+
+    /// How many rotations leave the dial pointing at `target`.
+    pub fn landings<'a>(
+        self, rotations: impl IntoIterator<Item = &'a Rotation>, target: u64,
+    ) -> usize {
+        self.trace(rotations)
+            .filter(|turn| turn.to == target)
+            .count()
+    }
+
+    /// How many clicks of all rotations point the dial at `target`.
+    pub fn crossings<'a>(
+        self, rotations: impl IntoIterator<Item = &'a Rotation>, target: u64,
+    ) -> u64 {
+        self.trace(rotations).map(|turn| turn.hits(target)).sum()
+    }
+}
+
+impl Turn {
+    /// How many clicks of the rotation point the dial at `target`, the last one
+    /// included and the starting position not.
+    pub fn hits(&self, target: u64) -> u64 {
+        if target >= self.modulus {
+            return 0;
+        }
+
+        // Clicks until the dial first points at the target.
+        let first = match self.rotation.direction {
+            Direction::Left => sub_mod(self.from, target, self.modulus),
+            Direction::Right => sub_mod(target, self.from, self.modulus),
+        };
+        let first = match first {
+            0 => self.modulus,
+            first => first,
+        };
+
+        match self.rotation.step.checked_sub(first) {
+            Some(rest) => rest / self.modulus + 1,
+            None => 0,
+        }
+    }
+}
+
+/// `(a - b) mod modulus` for `a` and `b` below the modulus, without overflowing even
+/// for moduli close to `u64::MAX`.
+fn sub_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    match a >= b {
+        true => a - b,
+        false => modulus - (b - a),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rotation(direction: Direction, step: u64) -> Rotation {
+        Rotation { direction, step }
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut dial = Dial::new(10, 13);
+        assert_eq!(dial.position(), 3);
+
+        let turn = dial.rotate(rotation(Direction::Left, 5));
+        assert_eq!((turn.from, turn.to), (3, 8));
+        assert_eq!(turn.hits(0), 1);
+        assert_eq!(turn.hits(8), 1);
+        assert_eq!(turn.hits(3), 0);
+
+        let turn = dial.rotate(rotation(Direction::Right, 25));
+        assert_eq!((turn.from, turn.to), (8, 3));
+        assert_eq!(turn.hits(9), 3);
+        assert_eq!(turn.hits(3), 3);
+        assert_eq!(turn.hits(5), 2);
+    }
+
+    #[test]
+    fn test_large_steps() {
+        let mut dial = Dial::new(100, 50);
+
+        let turn = dial.rotate(rotation(Direction::Right, u64::MAX));
+        assert_eq!(turn.to, 65);
+        assert_eq!(turn.hits(0), (u64::MAX - 50) / 100 + 1);
+
+        let mut dial = Dial::new(u64::MAX, u64::MAX - 1);
+        let turn = dial.rotate(rotation(Direction::Right, 3));
+        assert_eq!((turn.to, turn.hits(0)), (2, 1));
+        let turn = dial.rotate(rotation(Direction::Left, 3));
+        assert_eq!((turn.to, turn.hits(0)), (u64::MAX - 1, 1));
+    }
+
+    #[test]
+    fn test_trace() {
+        let rotations = [
+            rotation(Direction::Left, 68),
+            rotation(Direction::Left, 30),
+            rotation(Direction::Right, 48),
+        ];
+
+        let positions = Dial::new(100, 50)
+            .trace(&rotations)
+            .map(|turn| turn.to)
+            .collect::<Vec<u64>>();
+
+        assert_eq!(positions, vec![82, 52, 0]);
+        assert_eq!(Dial::new(100, 50).landings(&rotations, 0), 1);
+        assert_eq!(Dial::new(100, 50).crossings(&rotations, 0), 2);
+        assert_eq!(Dial::new(100, 50).landings(&rotations, 52), 1);
+    }
+}
@@ -1,3 +1,4 @@
+use common::errors::DayError;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -15,3 +16,7 @@ pub enum Day01Error {
     #[error("Part 1. Failed to parse integer from step value: {0}")]
     FailedParseInt(#[from] std::num::ParseIntError),
 }
+
+impl DayError for Day01Error {
+    const DAY: u8 = 1;
+}
@@ -0,0 +1,55 @@
+use crate::days::day01::errors::Day01Error;
+use common::diagnostic::{Diagnostic, Locate, Span};
+
+/// One line of the document: rotate the dial to the left or right by some clicks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation {
+    pub direction: Direction,
+    /// Clicks to turn the dial by.
+    pub step: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Rotation>, Diagnostic<Day01Error>> {
+    let mut rotations = Vec::new();
+
+    for line in input.lines() {
+        let direction_char = line
+            .chars()
+            .next()
+            .ok_or(Day01Error::InstructionNotFound)
+            .at(|| Span::locate(input, line))?;
+        let direction = Direction::try_from(direction_char)
+            .at(|| Span::locate(input, &line[..direction_char.len_utf8()]))?;
+        let step = line
+            .get(1..)
+            .ok_or(Day01Error::FailedGetStep)
+            .at(|| Span::locate(input, line))?;
+        let step = step
+            .trim()
+            .parse::<u64>()
+            .map_err(Day01Error::FailedParseInt)
+            .at(|| Span::locate(input, step))?;
+
+        rotations.push(Rotation { direction, step });
+    }
+
+    Ok(rotations)
+}
+
+impl TryFrom<char> for Direction {
+    type Error = Day01Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            invalid => Err(Day01Error::InvalidInstruction(invalid)),
+        }
+    }
+}
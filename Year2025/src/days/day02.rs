@@ -0,0 +1,40 @@
+use crate::YEAR;
+use common::answer::Answer;
+use common::errors::Error;
+use common::registry::Day;
+use common::solution::{Metadata, Solution};
+use std::ops::RangeInclusive;
+
+pub mod errors;
+pub mod model;
+pub mod part1; // 02.12.2025
+pub mod part2; // 02.12.2025
+pub mod repeated;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Model = Vec<RangeInclusive<u64>>;
+
+    const METADATA: Metadata = Metadata {
+        year: YEAR.number,
+        day: 2,
+        title: "Gift Shop",
+        date: "02.12.2025",
+        parts: 2,
+    };
+
+    fn parse(input: String) -> Result<Self::Model, Error> {
+        Ok(model::parse(&input)?)
+    }
+
+    fn part1(ranges: &Self::Model) -> Result<Answer, Error> {
+        part1::process(ranges)
+    }
+
+    fn part2(ranges: &Self::Model) -> Result<Answer, Error> {
+        part2::process(ranges)
+    }
+}
+
+inventory::submit! { Day::new::<Day02>() }
@@ -1,3 +1,4 @@
+use common::errors::DayError;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -9,3 +10,7 @@ pub enum Day02Error {
     #[error("Input is missing end number: {0}")]
     WithoutEndNumber(String),
 }
+
+impl DayError for Day02Error {
+    const DAY: u8 = 2;
+}
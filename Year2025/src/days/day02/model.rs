@@ -0,0 +1,30 @@
+use crate::days::day02::errors::Day02Error;
+use common::diagnostic::{Diagnostic, Locate, Span};
+use std::ops::RangeInclusive;
+
+/// Comma-separated ranges of product IDs, like `11-22`.
+pub fn parse(input: &str) -> Result<Vec<RangeInclusive<u64>>, Diagnostic<Day02Error>> {
+    let mut ranges = Vec::new();
+    for range in input.split(",") {
+        let bounds = range.split("-").collect::<Vec<&str>>();
+        let start = bounds
+            .first()
+            .ok_or(Day02Error::WithoutStartNumber(range.to_string()))
+            .at(|| Span::locate(input, range))?;
+        let start = start
+            .parse::<u64>()
+            .map_err(Day02Error::FailedToParseInt)
+            .at(|| Span::locate(input, start))?;
+        let end = bounds
+            .get(1)
+            .ok_or(Day02Error::WithoutEndNumber(range.to_string()))
+            .at(|| Span::locate(input, range))?;
+        let end = end
+            .parse::<u64>()
+            .map_err(Day02Error::FailedToParseInt)
+            .at(|| Span::locate(input, end))?;
+        ranges.push(start..=end);
+    }
+
+    Ok(ranges)
+}
@@ -0,0 +1,195 @@
+use std::ops::{Add, RangeInclusive, Sub};
+
+/// How many numbers matched, and their sum.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Matches {
+    pub count: u64,
+    pub sum: i128,
+}
+
+/// Numbers in the range whose digits are a block of `block` digits repeated `repeats`
+/// times, like `123123` for a block of 3 repeated twice. The block has no leading zero.
+pub fn enumerate(
+    range: &RangeInclusive<u64>, block: u32, repeats: u32,
+) -> impl Iterator<Item = u64> {
+    let (multiplier, blocks) = blocks(range, block, repeats);
+    blocks.map(move |block| (block * multiplier) as u64)
+}
+
+/// Count and sum of [`enumerate`], in constant time.
+pub fn repeated(range: &RangeInclusive<u64>, block: u32, repeats: u32) -> Matches {
+    let (multiplier, blocks) = blocks(range, block, repeats);
+    if blocks.is_empty() {
+        return Matches::default();
+    }
+
+    let count = blocks.end() - blocks.start() + 1;
+    Matches {
+        count: count as u64,
+        sum: (multiplier * (blocks.start() + blocks.end()) * count / 2) as i128,
+    }
+}
+
+/// Numbers in the range made of some block repeated exactly twice.
+pub fn doubled(range: &RangeInclusive<u64>) -> Matches {
+    lengths(range)
+        .filter(|length| length.is_multiple_of(2))
+        .map(|length| repeated(range, length / 2, 2))
+        .fold(Matches::default(), Add::add)
+}
+
+/// Numbers in the range made of some block repeated at least twice. Numbers with several
+/// periods, like `222222`, are counted once.
+pub fn periodic(range: &RangeInclusive<u64>) -> Matches {
+    let mut total = Matches::default();
+    for length in lengths(range) {
+        // A number of `length` digits is periodic if it repeats a block of
+        // `length / p` digits for a prime `p` dividing the length. Repeating blocks
+        // for several primes at once means repeating their common divisor, so the union
+        // is counted by inclusion–exclusion over the sets of primes.
+        let primes = prime_factors(length);
+        let (mut included, mut excluded) = (Matches::default(), Matches::default());
+        for subset in 1..1_u32 << primes.len() {
+            let (product, size) = primes
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| subset & (1 << index) != 0)
+                .fold((1, 0), |(product, size), (_, prime)| {
+                    (product * prime, size + 1)
+                });
+            let matches = repeated(range, length / product, product);
+            match size % 2 {
+                1 => included = included + matches,
+                _ => excluded = excluded + matches,
+            }
+        }
+        total = total + (included - excluded);
+    }
+    total
+}
+
+/// Multiplier turning a block into the repeated number, e.g. `1001` for a block of 3
+/// repeated twice, and the blocks whose repetitions lie in the range.
+fn blocks(
+    range: &RangeInclusive<u64>, block: u32, repeats: u32,
+) -> (u128, RangeInclusive<u128>) {
+    if block == 0 || block * repeats > MAX_DIGITS {
+        return (0, RangeInclusive::new(1, 0));
+    }
+
+    let multiplier = (10_u128.pow(block * repeats) - 1) / (10_u128.pow(block) - 1);
+    let first = (*range.start() as u128)
+        .div_ceil(multiplier)
+        .max(10_u128.pow(block - 1));
+    let last = (*range.end() as u128 / multiplier).min(10_u128.pow(block) - 1);
+    (multiplier, first..=last)
+}
+
+/// Digits of `u64::MAX`.
+const MAX_DIGITS: u32 = 20;
+
+/// Digit counts of the numbers in the range.
+fn lengths(range: &RangeInclusive<u64>) -> RangeInclusive<u32> {
+    let digits = |number: u64| number.checked_ilog10().unwrap_or(0) + 1;
+    digits(*range.start())..=digits(*range.end())
+}
+
+fn prime_factors(mut number: u32) -> Vec<u32> {
+    let mut primes = Vec::new();
+    let mut factor = 2;
+    while factor * factor <= number {
+        if number.is_multiple_of(factor) {
+            primes.push(factor);
+            while number.is_multiple_of(factor) {
+                number /= factor;
+            }
+        }
+        factor += 1;
+    }
+    if number > 1 {
+        primes.push(number);
+    }
+    primes
+}
+
+impl Add for Matches {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            count: self.count + other.count,
+            sum: self.sum + other.sum,
+        }
+    }
+}
+
+impl Sub for Matches {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            count: self.count - other.count,
+            sum: self.sum - other.sum,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Matches found by checking every number of the range.
+    fn brute_force(
+        range: RangeInclusive<u64>, periodic: impl Fn(&str) -> bool,
+    ) -> Matches {
+        range.filter(|number| periodic(&number.to_string())).fold(
+            Matches::default(),
+            |matches, number| Matches {
+                count: matches.count + 1,
+                sum: matches.sum + number as i128,
+            },
+        )
+    }
+
+    fn is_doubled(number: &str) -> bool {
+        let (left, right) = number.split_at(number.len() / 2);
+        left == right
+    }
+
+    fn is_periodic(number: &str) -> bool {
+        (1..number.len()).any(|block| {
+            number.len().is_multiple_of(block)
+                && number == number[..block].repeat(number.len() / block)
+        })
+    }
+
+    #[test]
+    fn test_enumerate() {
+        let numbers = enumerate(&(95..=1300), 1, 3).collect::<Vec<u64>>();
+        assert_eq!(numbers, vec![111, 222, 333, 444, 555, 666, 777, 888, 999]);
+
+        let numbers = enumerate(&(1000..=1300), 2, 2).collect::<Vec<u64>>();
+        assert_eq!(numbers, vec![1010, 1111, 1212]);
+        assert_eq!(repeated(&(1000..=1300), 2, 2).sum, 1010 + 1111 + 1212);
+    }
+
+    #[test]
+    fn test_against_brute_force() {
+        for range in [1..=100_000, 95..=115, 998..=1012, 222220..=222224, 5..=5] {
+            assert_eq!(doubled(&range), brute_force(range.clone(), is_doubled));
+            assert_eq!(periodic(&range), brute_force(range.clone(), is_periodic));
+        }
+    }
+
+    #[test]
+    fn test_wide_ranges() {
+        let range = 1..=u64::MAX;
+
+        // 9 blocks of 1 digit, 90 of 2, ..., and the 10 digit blocks up to
+        // 1844674407, whose doubles stay below u64::MAX.
+        assert_eq!(doubled(&range).count, 10_u64.pow(9) - 1 + 844_674_408);
+        assert_eq!(doubled(&(1..=u64::MAX - 1)), doubled(&range));
+        assert_eq!(periodic(&(4444385428..=4444484883)).count, 1);
+        assert!(periodic(&range).count > doubled(&range).count);
+    }
+}
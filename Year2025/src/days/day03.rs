@@ -0,0 +1,43 @@
+use crate::YEAR;
+use common::answer::Answer;
+use common::errors::Error;
+use common::explore::Command;
+use common::registry::Day;
+use common::solution::{Metadata, Solution};
+
+pub mod commands;
+pub mod errors;
+pub mod joltage;
+pub mod model;
+pub mod part1; // 03.12.2025
+pub mod part2; // 03.12.2025
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Model = Vec<Vec<u8>>;
+
+    const METADATA: Metadata = Metadata {
+        year: YEAR.number,
+        day: 3,
+        title: "Lobby",
+        date: "03.12.2025",
+        parts: 2,
+    };
+
+    const COMMANDS: &'static [Command<Self::Model>] = commands::COMMANDS;
+
+    fn parse(input: String) -> Result<Self::Model, Error> {
+        Ok(model::parse(&input)?)
+    }
+
+    fn part1(banks: &Self::Model) -> Result<Answer, Error> {
+        part1::process(banks)
+    }
+
+    fn part2(banks: &Self::Model) -> Result<Answer, Error> {
+        part2::process(banks)
+    }
+}
+
+inventory::submit! { Day::new::<Day03>() }
@@ -0,0 +1,18 @@
+use crate::days::day03::joltage::total;
+use common::errors::Error;
+use common::explore::{Command, CommandError, arguments, parse_argument};
+
+pub const COMMANDS: &[Command<Vec<Vec<u8>>>] = &[Command {
+    name: "joltage",
+    args: "K",
+    help: "Sum of the largest joltages of K batteries per bank, 2 and 12 in parts 1 and 2",
+    run: |banks, args| joltage(banks, args),
+}];
+
+fn joltage(banks: &[Vec<u8>], args: &[&str]) -> Result<String, CommandError> {
+    let [k] = arguments(args)?;
+    let k = parse_argument::<usize>(k)?;
+
+    let answer = total(banks, k).map_err(Error::from)?;
+    Ok(answer.to_string())
+}
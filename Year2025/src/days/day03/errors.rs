@@ -1,3 +1,4 @@
+use common::errors::DayError;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -7,7 +8,8 @@ pub enum Day03Error {
 
     #[error("Failed to convert joltage: {0}")]
     FailedToConvertJoltage(#[from] std::num::ParseIntError),
+}
 
-    #[error("Failed to find answer")]
-    ValueNotFound,
+impl DayError for Day03Error {
+    const DAY: u8 = 3;
 }
@@ -0,0 +1,105 @@
+use crate::days::day03::errors::Day03Error;
+use common::answer::{Answer, BigInt};
+
+/// Digits of the largest number formed by turning on `k` batteries of the bank, kept in
+/// their order. `None` if the bank has fewer than `k` batteries.
+///
+/// Greedy in linear time: a battery replaces the smaller ones before it as long as
+/// enough batteries are left to still reach `k`.
+pub fn largest(bank: &[u8], k: usize) -> Option<Vec<u8>> {
+    let mut skips = bank.len().checked_sub(k)?;
+    let mut stack = Vec::with_capacity(bank.len());
+    for &joltage in bank {
+        while skips > 0 && stack.last().is_some_and(|&top| top < joltage) {
+            stack.pop();
+            skips -= 1;
+        }
+        stack.push(joltage);
+    }
+    stack.truncate(k);
+    Some(stack)
+}
+
+/// Sum of the largest `k`-digit joltages of the banks. Any `k` works: sums beyond 128
+/// bits are added digit by digit.
+pub fn total(banks: &[Vec<u8>], k: usize) -> Result<Answer, Day03Error> {
+    let joltages = banks
+        .iter()
+        .map(|bank| {
+            largest(bank, k).ok_or(Day03Error::InvalidAmountOfBatteries(bank.len()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let sum = joltages.iter().try_fold(0_u128, |sum, joltage| {
+        let joltage = joltage.iter().try_fold(0_u128, |number, &digit| {
+            number.checked_mul(10)?.checked_add(digit as u128)
+        })?;
+        sum.checked_add(joltage)
+    });
+    if let Some(sum) = sum {
+        return Ok(u64::try_from(sum).map_or_else(|_| sum.into(), Answer::from));
+    }
+
+    // Least significant digit first.
+    let mut sum = Vec::new();
+    for joltage in &joltages {
+        add(&mut sum, joltage);
+    }
+    sum.reverse();
+    Ok(BigInt::from_digits(&sum).into())
+}
+
+/// Adds the number with the given digits to a sum stored least significant digit first.
+fn add(sum: &mut Vec<u8>, digits: &[u8]) {
+    let mut digits = digits.iter().rev();
+    let mut carry = 0;
+    let mut index = 0;
+    loop {
+        let digit = digits.next();
+        if digit.is_none() && carry == 0 {
+            return;
+        }
+        if index == sum.len() {
+            sum.push(0);
+        }
+        let place = sum[index] + digit.unwrap_or(&0) + carry;
+        (sum[index], carry) = (place % 10, place / 10);
+        index += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_largest() {
+        let bank = [8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1];
+        assert_eq!(largest(&bank, 2), Some(vec![9, 2]));
+        assert_eq!(
+            largest(&bank, 12),
+            Some(vec![8, 8, 8, 9, 1, 1, 1, 1, 2, 1, 1, 1])
+        );
+        assert_eq!(largest(&bank, 15).as_deref(), Some(&bank[..]));
+        assert_eq!(largest(&bank, 0), Some(vec![]));
+        assert_eq!(largest(&bank, 16), None);
+    }
+
+    #[test]
+    fn test_total() {
+        let banks = vec![vec![9; 40], vec![1; 40]];
+
+        assert_eq!(total(&banks, 2).unwrap(), "110");
+        assert_eq!(total(&banks, 38).unwrap(), "1".repeat(38) + "0");
+        assert_eq!(total(&banks, 39).unwrap(), "1".repeat(39) + "0");
+        assert_eq!(total(&banks, 40).unwrap(), "1".repeat(40) + "0");
+        assert_eq!(
+            total(&vec![vec![9; 50]; 11], 50).unwrap(),
+            format!("10{}89", "9".repeat(48))
+        );
+        assert!(matches!(
+            total(&banks, 41),
+            Err(Day03Error::InvalidAmountOfBatteries(40))
+        ));
+    }
+}
@@ -0,0 +1,22 @@
+use crate::days::day03::errors::Day03Error;
+use common::diagnostic::{Diagnostic, Locate, Span};
+
+/// Banks of batteries, one per line, each battery given by its joltage digit.
+pub fn parse(input: &str) -> Result<Vec<Vec<u8>>, Diagnostic<Day03Error>> {
+    let mut banks = Vec::new();
+
+    for line in input.lines() {
+        let mut bank = Vec::with_capacity(line.len());
+        for (index, c) in line.char_indices() {
+            let joltage = c
+                .to_string()
+                .parse::<u8>()
+                .map_err(Day03Error::FailedToConvertJoltage)
+                .at(|| Span::locate(input, &line[index..index + c.len_utf8()]))?;
+            bank.push(joltage);
+        }
+        banks.push(bank);
+    }
+
+    Ok(banks)
+}
@@ -0,0 +1,43 @@
+use crate::YEAR;
+use common::answer::Answer;
+use common::errors::Error;
+use common::explore::Command;
+use common::grid::Grid;
+use common::registry::Day;
+use common::solution::{Metadata, Solution};
+
+pub mod commands;
+pub mod errors;
+pub mod model;
+pub mod part1; // 04.12.2025
+pub mod part2; // 04.12.2025
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Model = Grid<model::Item>;
+
+    const METADATA: Metadata = Metadata {
+        year: YEAR.number,
+        day: 4,
+        title: "Printing Department",
+        date: "04.12.2025",
+        parts: 2,
+    };
+
+    const COMMANDS: &'static [Command<Self::Model>] = commands::COMMANDS;
+
+    fn parse(input: String) -> Result<Self::Model, Error> {
+        Ok(model::parse(&input)?)
+    }
+
+    fn part1(grid: &Self::Model) -> Result<Answer, Error> {
+        part1::process(grid)
+    }
+
+    fn part2(grid: &Self::Model) -> Result<Answer, Error> {
+        part2::process(grid)
+    }
+}
+
+inventory::submit! { Day::new::<Day04>() }
@@ -0,0 +1,16 @@
+use crate::days::day04::model::Item;
+use common::explore::{Command, CommandError, arguments};
+use common::grid::Grid;
+
+pub const COMMANDS: &[Command<Grid<Item>>] = &[Command {
+    name: "show",
+    args: "",
+    help: "Prints the grid of paper rolls",
+    run: show,
+}];
+
+fn show(grid: &Grid<Item>, args: &[&str]) -> Result<String, CommandError> {
+    let [] = arguments(args)?;
+
+    Ok(grid.to_string())
+}
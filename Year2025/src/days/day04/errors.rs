@@ -1,3 +1,4 @@
+use common::errors::DayError;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -8,3 +9,7 @@ pub enum Day04Error {
     #[error("Invalid item character: {0}")]
     UnknownItem(char),
 }
+
+impl DayError for Day04Error {
+    const DAY: u8 = 4;
+}
@@ -0,0 +1,36 @@
+use crate::days::day04::errors::Day04Error;
+use common::diagnostic::Diagnostic;
+use common::grid::Grid;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Item {
+    RollOfPaper,
+    Empty,
+}
+
+pub fn parse(input: &str) -> Result<Grid<Item>, Diagnostic<Day04Error>> {
+    Grid::parse(input, Item::try_from)
+        .map_err(|err| Diagnostic::from_grid(input, err, || Day04Error::InvalidGridSize))
+}
+
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Item::RollOfPaper => write!(f, "@"),
+            Item::Empty => write!(f, "."),
+        }
+    }
+}
+
+impl TryFrom<char> for Item {
+    type Error = Day04Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '@' => Ok(Item::RollOfPaper),
+            '.' => Ok(Item::Empty),
+            _ => Err(Day04Error::UnknownItem(value)),
+        }
+    }
+}
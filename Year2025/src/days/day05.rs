@@ -0,0 +1,42 @@
+use crate::YEAR;
+use common::answer::Answer;
+use common::errors::Error;
+use common::explore::Command;
+use common::registry::Day;
+use common::solution::{Metadata, Solution};
+
+pub mod commands;
+pub mod errors;
+pub mod model;
+pub mod part1; // 05.12.2025
+pub mod part2; // 05.12.2025
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Model = model::Inventory;
+
+    const METADATA: Metadata = Metadata {
+        year: YEAR.number,
+        day: 5,
+        title: "Cafeteria",
+        date: "05.12.2025",
+        parts: 2,
+    };
+
+    const COMMANDS: &'static [Command<Self::Model>] = commands::COMMANDS;
+
+    fn parse(input: String) -> Result<Self::Model, Error> {
+        Ok(model::parse(&input)?)
+    }
+
+    fn part1(inventory: &Self::Model) -> Result<Answer, Error> {
+        part1::process(inventory)
+    }
+
+    fn part2(inventory: &Self::Model) -> Result<Answer, Error> {
+        part2::process(inventory)
+    }
+}
+
+inventory::submit! { Day::new::<Day05>() }
@@ -0,0 +1,25 @@
+use crate::days::day05::model::Inventory;
+use common::explore::{Command, CommandError, arguments, parse_argument};
+
+pub const COMMANDS: &[Command<Inventory>] = &[Command {
+    name: "contains",
+    args: "ID",
+    help: "Fresh ranges that contain the ingredient ID",
+    run: contains,
+}];
+
+fn contains(inventory: &Inventory, args: &[&str]) -> Result<String, CommandError> {
+    let [id] = arguments(args)?;
+    let id = parse_argument::<u64>(id)?;
+
+    let ranges = inventory
+        .ranges
+        .iter()
+        .filter(|range| range.contains(&id))
+        .map(|range| format!("{}-{}", range.start(), range.end()))
+        .collect::<Vec<String>>();
+    match ranges.is_empty() {
+        true => Ok("Spoiled, no range contains it".to_string()),
+        false => Ok(format!("Fresh: {}", ranges.join(", "))),
+    }
+}
@@ -1,3 +1,4 @@
+use common::errors::DayError;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -8,3 +9,7 @@ pub enum Day05Error {
     #[error("Invalid range format")]
     InvalidRangeFormat,
 }
+
+impl DayError for Day05Error {
+    const DAY: u8 = 5;
+}
@@ -0,0 +1,63 @@
+use crate::days::day05::errors::Day05Error;
+use common::diagnostic::{Diagnostic, Locate, Span};
+use std::ops::RangeInclusive;
+
+/// Fresh ingredient ID ranges, then the available ingredient IDs after a blank line.
+#[derive(Debug)]
+pub struct Inventory {
+    pub ranges: Vec<RangeInclusive<u64>>,
+    pub ids: Vec<u64>,
+}
+
+pub fn parse(input: &str) -> Result<Inventory, Diagnostic<Day05Error>> {
+    let mut ranges: Vec<RangeInclusive<u64>> = vec![];
+    let mut ids: Vec<u64> = vec![];
+
+    let mut are_we_parsing_ids = false;
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            are_we_parsing_ids = true;
+            continue;
+        }
+
+        match are_we_parsing_ids {
+            false => {
+                let parts: Vec<&str> = line.split('-').collect();
+                if parts.len() != 2 {
+                    return Err(Day05Error::InvalidRangeFormat)
+                        .at(|| Span::locate(input, line));
+                }
+                let start = parts
+                    .first()
+                    .ok_or(Day05Error::InvalidRangeFormat)
+                    .at(|| Span::locate(input, line))?;
+                let start: u64 = start
+                    .trim()
+                    .parse()
+                    .map_err(Day05Error::FailedToParseInt)
+                    .at(|| Span::locate(input, start))?;
+                let end = parts
+                    .get(1)
+                    .ok_or(Day05Error::InvalidRangeFormat)
+                    .at(|| Span::locate(input, line))?;
+                let end: u64 = end
+                    .trim()
+                    .parse()
+                    .map_err(Day05Error::FailedToParseInt)
+                    .at(|| Span::locate(input, end))?;
+
+                ranges.push(RangeInclusive::new(start, end));
+            },
+            true => {
+                let id: u64 = line
+                    .trim()
+                    .parse()
+                    .map_err(Day05Error::FailedToParseInt)
+                    .at(|| Span::locate(input, line))?;
+                ids.push(id);
+            },
+        }
+    }
+
+    Ok(Inventory { ranges, ids })
+}
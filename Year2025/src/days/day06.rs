@@ -0,0 +1,38 @@
+use crate::YEAR;
+use common::answer::Answer;
+use common::errors::Error;
+use common::registry::Day;
+use common::solution::{Metadata, Solution};
+
+pub mod errors;
+pub mod model;
+pub mod part1; // 06.12.2025
+pub mod part2; // 06.12.2025
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Model = Vec<model::Problem>;
+
+    const METADATA: Metadata = Metadata {
+        year: YEAR.number,
+        day: 6,
+        title: "Trash Compactor",
+        date: "06.12.2025",
+        parts: 2,
+    };
+
+    fn parse(input: String) -> Result<Self::Model, Error> {
+        Ok(model::parse(&input)?)
+    }
+
+    fn part1(problems: &Self::Model) -> Result<Answer, Error> {
+        part1::process(problems)
+    }
+
+    fn part2(problems: &Self::Model) -> Result<Answer, Error> {
+        part2::process(problems)
+    }
+}
+
+inventory::submit! { Day::new::<Day06>() }
@@ -1,3 +1,4 @@
+use common::errors::DayError;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -8,3 +9,7 @@ pub enum Day06Error {
     #[error("Invalid operation: {0}")]
     InvalidOperation(String),
 }
+
+impl DayError for Day06Error {
+    const DAY: u8 = 6;
+}
@@ -0,0 +1,95 @@
+use crate::days::day06::errors::Day06Error;
+use common::diagnostic::{Diagnostic, Locate, Span};
+
+/// One problem of the worksheet: its block of columns in every number row and the
+/// operation below them. The rows keep their alignment, since the parts read the digits
+/// in different directions.
+#[derive(Debug)]
+pub struct Problem {
+    pub rows: Vec<String>,
+    pub operation: Operation,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Operation {
+    Plus,
+    Multiply,
+}
+
+impl Operation {
+    pub fn apply(self, numbers: impl Iterator<Item = u64>) -> u64 {
+        match self {
+            Operation::Plus => numbers.sum(),
+            Operation::Multiply => numbers.product(),
+        }
+    }
+}
+
+/// Problems are separated by columns that are blank in every line.
+pub fn parse(input: &str) -> Result<Vec<Problem>, Diagnostic<Day06Error>> {
+    let lines = input.lines().collect::<Vec<&str>>();
+    let Some((operations, number_lines)) = lines.split_last() else {
+        return Ok(Vec::new());
+    };
+
+    for line in number_lines {
+        for value in line.split_whitespace() {
+            if !value.chars().all(|symbol| symbol.is_ascii_digit()) {
+                return Err(Day06Error::InvalidNumber(value.to_string()))
+                    .at(|| Span::locate(input, value));
+            }
+        }
+    }
+
+    let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+    let is_separator = |column: usize| {
+        lines.iter().all(|line| {
+            line.as_bytes()
+                .get(column)
+                .is_none_or(|symbol| symbol.is_ascii_whitespace())
+        })
+    };
+    let block = |line: &str, start: usize, end: usize| {
+        line.get(start.min(line.len())..end.min(line.len()))
+            .unwrap_or_default()
+            .to_string()
+    };
+
+    let mut problems = Vec::new();
+    let mut start = 0;
+    for column in 0..=width {
+        if column < width && !is_separator(column) {
+            continue;
+        }
+
+        if column > start {
+            let operation = block(operations, start, column);
+            let operation = Operation::try_from(operation.as_str()).at(|| {
+                let line = lines.len() - 1;
+                Span::new(line, operations, start..column.min(operations.len()))
+            })?;
+            problems.push(Problem {
+                rows: number_lines
+                    .iter()
+                    .map(|line| block(line, start, column))
+                    .collect(),
+                operation,
+            });
+        }
+        start = column + 1;
+    }
+
+    Ok(problems)
+}
+
+impl TryFrom<&str> for Operation {
+    type Error = Day06Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim() {
+            "+" => Ok(Operation::Plus),
+            "*" => Ok(Operation::Multiply),
+            _ => Err(Day06Error::InvalidOperation(value.to_string())),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::YEAR;
+use common::answer::Answer;
+use common::errors::Error;
+use common::registry::Day;
+use common::solution::{Metadata, Solution};
+
+pub mod errors;
+pub mod model;
+pub mod part1; // 07.12.2025
+pub mod part2; // 07.12.2025
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Model = model::Manifold;
+
+    const METADATA: Metadata = Metadata {
+        year: YEAR.number,
+        day: 7,
+        title: "Laboratories",
+        date: "07.12.2025",
+        parts: 2,
+    };
+
+    fn parse(input: String) -> Result<Self::Model, Error> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(manifold: &Self::Model) -> Result<Answer, Error> {
+        part1::process(manifold)
+    }
+
+    fn part2(manifold: &Self::Model) -> Result<Answer, Error> {
+        part2::process(manifold)
+    }
+}
+
+inventory::submit! { Day::new::<Day07>() }
@@ -1,3 +1,4 @@
+use common::errors::DayError;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -5,9 +6,16 @@ pub enum Day07Error {
     #[error("Invalid item character: {0}")]
     InvalidItem(char),
 
+    #[error("Rows of the grid have different lengths")]
+    InvalidGridSize,
+
     #[error("Start position not found in the grid")]
     StartNotFound,
 
     #[error("Unexpected start position at [{0}, {1}]")]
     UnexpectedStart(usize, usize),
 }
+
+impl DayError for Day07Error {
+    const DAY: u8 = 7;
+}
@@ -0,0 +1,50 @@
+use crate::days::day07::errors::Day07Error;
+use common::diagnostic::{Diagnostic, Locate, Span};
+use common::grid::{Grid, Position};
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Manifold {
+    pub grid: Grid<Item>,
+    pub start: Position,
+}
+
+impl FromStr for Manifold {
+    type Err = Diagnostic<Day07Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::parse(s, Item::try_from).map_err(|err| {
+            Diagnostic::from_grid(s, err, || Day07Error::InvalidGridSize)
+        })?;
+
+        let start = grid
+            .find(|item| *item == Item::Start)
+            .ok_or(Day07Error::StartNotFound)
+            .at(|| Span::locate(s, s.lines().next().unwrap_or(s)))?;
+
+        Ok(Manifold { grid, start })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Item {
+    Empty,
+    Start,
+    Splitter,
+
+    // Not in the input, part 1 marks the beams with it
+    Beam,
+}
+
+impl TryFrom<char> for Item {
+    type Error = Day07Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Item::Empty),
+            'S' => Ok(Item::Start),
+            '^' => Ok(Item::Splitter),
+            _ => Err(Self::Error::InvalidItem(value)),
+        }
+    }
+}
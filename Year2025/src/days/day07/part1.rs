@@ -1,20 +1,19 @@
 use crate::days::day07::errors::Day07Error;
-use crate::errors::Error;
-use std::str::FromStr;
+use crate::days::day07::model::{Item, Manifold};
+use common::answer::Answer;
+use common::errors::Error;
+use common::grid::{Grid, Position};
 
-// Answer: 1628
+pub fn process(manifold: &Manifold) -> Result<Answer, Error> {
+    let mut grid = manifold.grid.clone();
 
-pub fn process(input: String) -> Result<String, Error> {
-    let mut grid: Grid = input.parse()?;
+    let beams = process_path(&mut grid, manifold.start)?;
 
-    let start_index = grid.start_index;
-    let beams = process_path(&mut grid, (0, start_index))?;
-
-    Ok(beams.to_string())
+    Ok(beams.into())
 }
 
 fn process_path(
-    grid: &mut Grid, start_from: (usize, usize),
+    grid: &mut Grid<Item>, start_from: Position,
 ) -> Result<usize, Day07Error> {
     let mut splits: usize = 0;
 
@@ -22,14 +21,14 @@ fn process_path(
     loop {
         row += 1; // Move down one row
 
-        if row >= grid.rows {
+        if row >= grid.rows() {
             break; // Reached the bottom of the grid
         }
 
-        let element = grid.map[row][col].clone();
+        let element = grid[(row, col)].clone();
         match element {
             Item::Empty => {
-                grid.map[row][col] = Item::Beam;
+                grid[(row, col)] = Item::Beam;
             },
             Item::Splitter => {
                 // Left and right
@@ -39,12 +38,12 @@ fn process_path(
                         continue;
                     }
 
-                    let side_element = &grid.map[row][candidate as usize];
+                    let side_element = &grid[(row, candidate as usize)];
                     if side_element.eq(&Item::Beam) {
                         continue;
                     }
 
-                    grid.map[row][col] = Item::Beam;
+                    grid[(row, col)] = Item::Beam;
                     let inner_splits = process_path(grid, (row, candidate as usize))?;
                     splits += inner_splits;
                 }
@@ -62,107 +61,6 @@ fn process_path(
     Ok(splits)
 }
 
-fn is_column_exists(index: i32, grid: &Grid) -> bool {
-    index >= 0 && index < grid.columns as i32
-}
-
-#[derive(Debug)]
-pub struct Grid {
-    map: Vec<Vec<Item>>,
-    rows: usize,
-    columns: usize,
-    start_index: usize,
-}
-
-impl FromStr for Grid {
-    type Err = Day07Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut map = Vec::new();
-
-        for line in s.lines() {
-            let mut row = Vec::new();
-            for ch in line.chars() {
-                row.push(Item::try_from(ch)?);
-            }
-            map.push(row);
-        }
-
-        let rows = map.len();
-        let columns = if rows > 0 { map[0].len() } else { 0 };
-        let mut start_index: Option<usize> = None;
-        for row in &map {
-            debug_assert_eq!(row.len(), columns);
-            if start_index.is_none() {
-                for (index, item) in row.iter().enumerate() {
-                    if let Item::Start = item {
-                        start_index = Some(index);
-                        break;
-                    }
-                }
-            }
-        }
-
-        let start_index = start_index.ok_or(Day07Error::StartNotFound)?;
-
-        Ok(Grid {
-            map,
-            rows,
-            columns,
-            start_index,
-        })
-    }
-}
-
-#[derive(Debug, Clone, PartialEq)]
-enum Item {
-    Empty,
-    Start,
-    Splitter,
-
-    // Reserved for task
-    Beam,
-}
-
-impl TryFrom<char> for Item {
-    type Error = Day07Error;
-
-    fn try_from(value: char) -> Result<Self, Self::Error> {
-        match value {
-            '.' => Ok(Item::Empty),
-            'S' => Ok(Item::Start),
-            '^' => Ok(Item::Splitter),
-            _ => Err(Self::Error::InvalidItem(value)),
-        }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_1() {
-        let input = ".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............";
-
-        let actual = process(input.to_string()).unwrap();
-        let expected = "21".to_string();
-
-        assert_eq!(actual, expected);
-    }
+fn is_column_exists(index: i32, grid: &Grid<Item>) -> bool {
+    index >= 0 && index < grid.columns() as i32
 }
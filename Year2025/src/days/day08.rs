@@ -0,0 +1,38 @@
+use crate::YEAR;
+use common::answer::Answer;
+use common::errors::Error;
+use common::registry::Day;
+use common::solution::{Metadata, Solution};
+
+pub mod errors;
+pub mod model;
+pub mod part1; // 08.12.2025
+pub mod part2; // 08.12.2025
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Model = Vec<model::JunctionBox>;
+
+    const METADATA: Metadata = Metadata {
+        year: YEAR.number,
+        day: 8,
+        title: "Playground",
+        date: "08.12.2025",
+        parts: 2,
+    };
+
+    fn parse(input: String) -> Result<Self::Model, Error> {
+        Ok(model::parse(&input)?)
+    }
+
+    fn part1(junction_boxes: &Self::Model) -> Result<Answer, Error> {
+        part1::process(junction_boxes)
+    }
+
+    fn part2(junction_boxes: &Self::Model) -> Result<Answer, Error> {
+        part2::process(junction_boxes)
+    }
+}
+
+inventory::submit! { Day::new::<Day08>() }
@@ -1,3 +1,4 @@
+use common::errors::DayError;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -8,3 +9,7 @@ pub enum Day08Error {
     #[error("Invalid coordinate value: {0}")]
     InvalidCoordinate(#[from] std::num::ParseIntError),
 }
+
+impl DayError for Day08Error {
+    const DAY: u8 = 8;
+}
@@ -0,0 +1,47 @@
+use crate::days::day08::errors::Day08Error;
+use common::diagnostic::{Diagnostic, Locate, Span};
+use std::str::FromStr;
+
+pub fn parse(input: &str) -> Result<Vec<JunctionBox>, Diagnostic<Day08Error>> {
+    let mut junction_boxes = Vec::new();
+
+    for line in input.lines() {
+        let parts_str: Vec<&str> = line.split(',').collect();
+        if parts_str.len() != 3 {
+            return Err(Day08Error::InvalidInputFormat(line.to_string()))
+                .at(|| Span::locate(input, line));
+        }
+
+        let mut parts: [usize; 3] = [0; 3];
+        for (i, part) in parts_str.iter().enumerate() {
+            parts[i] = usize::from_str(part.trim())
+                .map_err(Day08Error::InvalidCoordinate)
+                .at(|| Span::locate(input, part))?;
+        }
+
+        junction_boxes.push(JunctionBox {
+            x: parts[0],
+            y: parts[1],
+            z: parts[2],
+        });
+    }
+
+    Ok(junction_boxes)
+}
+
+#[derive(Debug, Clone)]
+pub struct JunctionBox {
+    pub x: usize,
+    pub y: usize,
+    pub z: usize,
+}
+
+impl JunctionBox {
+    pub fn distance_to(&self, other: &Self) -> f64 {
+        let distance = ((self.x as isize - other.x as isize).pow(2)
+            + (self.y as isize - other.y as isize).pow(2)
+            + (self.z as isize - other.z as isize).pow(2)) as f64;
+
+        distance.sqrt()
+    }
+}
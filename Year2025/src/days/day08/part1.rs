@@ -1,16 +1,13 @@
-use crate::days::day08::errors::Day08Error;
-use crate::errors::Error;
+use crate::days::day08::model::JunctionBox;
+use common::answer::Answer;
+use common::errors::Error;
 use std::collections::HashMap;
-use std::str::FromStr;
 
-// Answer: 54600
-
-pub fn process(input: String) -> Result<String, Error> {
-    let junction_boxes = parse_input(&input)?;
-    let connections = connect_junkboxes(&junction_boxes, 1000);
+pub fn process(junction_boxes: &[JunctionBox]) -> Result<Answer, Error> {
+    let connections = connect_junkboxes(junction_boxes, 1000);
     let production = find_three_largest_production(connections);
 
-    Ok(production.to_string())
+    Ok(production.into())
 }
 
 fn connect_junkboxes(
@@ -68,58 +65,17 @@ fn find_three_largest_production(connections: Vec<usize>) -> usize {
     how_many[0] * how_many[1] * how_many[2]
 }
 
-fn parse_input(input: &str) -> Result<Vec<JunctionBox>, Day08Error> {
-    let mut junction_boxes = Vec::new();
-
-    for line in input.lines() {
-        let parts_str: Vec<&str> = line.split(',').collect();
-        if parts_str.len() != 3 {
-            return Err(Day08Error::InvalidInputFormat(line.to_string()));
-        }
-
-        let mut parts: [usize; 3] = [0; 3];
-        for (i, part) in parts_str.iter().enumerate() {
-            parts[i] =
-                usize::from_str(part.trim()).map_err(Day08Error::InvalidCoordinate)?;
-        }
-
-        junction_boxes.push(JunctionBox {
-            x: parts[0],
-            y: parts[1],
-            z: parts[2],
-        });
-    }
-
-    Ok(junction_boxes)
-}
-
-#[derive(Debug)]
-pub struct JunctionBox {
-    pub x: usize,
-    pub y: usize,
-    pub z: usize,
-}
-
-impl JunctionBox {
-    pub fn distance_to(&self, other: &Self) -> f64 {
-        let distance = ((self.x as isize - other.x as isize).pow(2)
-            + (self.y as isize - other.y as isize).pow(2)
-            + (self.z as isize - other.z as isize).pow(2)) as f64;
-
-        distance.sqrt()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::days::day08::model::parse;
 
-    fn process_test(input: &str) -> Result<String, Error> {
-        let junction_boxes = parse_input(&input)?;
+    fn process_test(input: &str) -> Result<Answer, Error> {
+        let junction_boxes = parse(input)?;
         let connections = connect_junkboxes(&junction_boxes, MAX_PAIRS);
         let production = find_three_largest_production(connections);
 
-        Ok(production.to_string())
+        Ok(production.into())
     }
 
     const MAX_PAIRS: usize = 10;
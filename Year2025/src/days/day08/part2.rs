@@ -1,15 +1,12 @@
-use crate::days::day08::errors::Day08Error;
-use crate::errors::Error;
+use crate::days::day08::model::JunctionBox;
+use common::answer::Answer;
+use common::errors::Error;
 use std::collections::HashMap;
-use std::str::FromStr;
 
-// Answer: 107256172
+pub fn process(junction_boxes: &[JunctionBox]) -> Result<Answer, Error> {
+    let result = solve(junction_boxes);
 
-pub fn process(input: String) -> Result<String, Error> {
-    let junction_boxes = parse_input(&input)?;
-    let result = solve(&junction_boxes);
-
-    Ok(result.to_string())
+    Ok(result.into())
 }
 
 fn solve(junction_boxes: &[JunctionBox]) -> usize {
@@ -48,79 +45,3 @@ fn solve(junction_boxes: &[JunctionBox]) -> usize {
 
     last_connected.0.x * last_connected.1.x
 }
-
-fn parse_input(input: &str) -> Result<Vec<JunctionBox>, Day08Error> {
-    let mut junction_boxes = Vec::new();
-
-    for line in input.lines() {
-        let parts_str: Vec<&str> = line.split(',').collect();
-        if parts_str.len() != 3 {
-            return Err(Day08Error::InvalidInputFormat(line.to_string()));
-        }
-
-        let mut parts: [usize; 3] = [0; 3];
-        for (i, part) in parts_str.iter().enumerate() {
-            parts[i] =
-                usize::from_str(part.trim()).map_err(Day08Error::InvalidCoordinate)?;
-        }
-
-        junction_boxes.push(JunctionBox {
-            x: parts[0],
-            y: parts[1],
-            z: parts[2],
-        });
-    }
-
-    Ok(junction_boxes)
-}
-
-#[derive(Debug, Clone)]
-pub struct JunctionBox {
-    pub x: usize,
-    pub y: usize,
-    pub z: usize,
-}
-
-impl JunctionBox {
-    pub fn distance_to(&self, other: &Self) -> f64 {
-        let distance = ((self.x as isize - other.x as isize).pow(2)
-            + (self.y as isize - other.y as isize).pow(2)
-            + (self.z as isize - other.z as isize).pow(2)) as f64;
-
-        distance.sqrt()
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_1() {
-        let input = "162,817,812
-57,618,57
-906,360,560
-592,479,940
-352,342,300
-466,668,158
-542,29,236
-431,825,988
-739,650,466
-52,470,668
-216,146,977
-819,987,18
-117,168,530
-805,96,715
-346,949,466
-970,615,88
-941,993,340
-862,61,35
-984,92,344
-425,690,689";
-
-        let actual = process(input.to_string()).unwrap();
-        let expected = "25272".to_string();
-
-        assert_eq!(actual, expected);
-    }
-}
@@ -0,0 +1,38 @@
+use crate::YEAR;
+use common::answer::Answer;
+use common::errors::Error;
+use common::registry::Day;
+use common::solution::{Metadata, Solution};
+
+pub mod errors;
+pub mod model;
+pub mod part1; // 09.12.2025
+pub mod part2; // 09.12.2025
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Model = Vec<model::Tile>;
+
+    const METADATA: Metadata = Metadata {
+        year: YEAR.number,
+        day: 9,
+        title: "Movie Theater",
+        date: "09.12.2025",
+        parts: 2,
+    };
+
+    fn parse(input: String) -> Result<Self::Model, Error> {
+        Ok(model::parse(&input)?)
+    }
+
+    fn part1(tiles: &Self::Model) -> Result<Answer, Error> {
+        part1::process(tiles)
+    }
+
+    fn part2(tiles: &Self::Model) -> Result<Answer, Error> {
+        part2::process(tiles)
+    }
+}
+
+inventory::submit! { Day::new::<Day09>() }
@@ -1,3 +1,4 @@
+use common::errors::{Category, DayError};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -11,3 +12,14 @@ pub enum Day09Error {
     #[error("Missing coordinate in set: {0}")]
     MissingCoordinateInSet(usize),
 }
+
+impl DayError for Day09Error {
+    const DAY: u8 = 9;
+
+    fn category(&self) -> Category {
+        match self {
+            Day09Error::MissingCoordinateInSet(_) => Category::Solver,
+            _ => Category::Parse,
+        }
+    }
+}
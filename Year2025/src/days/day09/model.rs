@@ -0,0 +1,44 @@
+use crate::days::day09::errors::Day09Error;
+use common::diagnostic::{Diagnostic, Locate, Span};
+use std::str::FromStr;
+
+/// Red tiles, in the order they are connected.
+pub fn parse(input: &str) -> Result<Vec<Tile>, Diagnostic<Day09Error>> {
+    let mut tiles = Vec::new();
+
+    for line in input.lines() {
+        let parts: Vec<&str> = line.split(',').collect();
+        if parts.len() != 2 {
+            return Err(Day09Error::InvalidInputFormat).at(|| Span::locate(input, line));
+        }
+
+        let mut coordinates: [usize; 2] = [0; 2];
+        for (i, coordinate) in parts.iter().enumerate() {
+            coordinates[i] = usize::from_str(coordinate.trim())
+                .map_err(Day09Error::InvalidCoordinate)
+                .at(|| Span::locate(input, coordinate))?;
+        }
+
+        tiles.push(Tile {
+            x: coordinates[0],
+            y: coordinates[1],
+        });
+    }
+
+    Ok(tiles)
+}
+
+#[derive(Debug)]
+pub struct Tile {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Tile {
+    pub fn area(&self, other_corner: &Self) -> usize {
+        // Use inclusive calculation: (|x2-x1| + 1) * (|y2-y1| + 1)
+        ((isize::abs(other_corner.x as isize - self.x as isize) + 1)
+            * (isize::abs(other_corner.y as isize - self.y as isize) + 1))
+            as usize
+    }
+}
@@ -1,14 +1,11 @@
-use crate::days::day09::errors::Day09Error;
-use crate::errors::Error;
-use std::str::FromStr;
+use crate::days::day09::model::Tile;
+use common::answer::Answer;
+use common::errors::Error;
 
-// Answer: 4749929916
+pub fn process(tiles: &[Tile]) -> Result<Answer, Error> {
+    let area = find_largest_area(tiles);
 
-pub fn process(input: String) -> Result<String, Error> {
-    let tiles = parse_input(&input)?;
-    let area = find_largest_area(&tiles);
-
-    Ok(area.to_string())
+    Ok(area.into())
 }
 
 fn find_largest_area(tiles: &[Tile]) -> usize {
@@ -25,62 +22,3 @@ fn find_largest_area(tiles: &[Tile]) -> usize {
 
     largest_area
 }
-
-fn parse_input(input: &str) -> Result<Vec<Tile>, Day09Error> {
-    let mut tiles = Vec::new();
-
-    for line in input.lines() {
-        let parts: Vec<&str> = line.split(',').collect();
-        if parts.len() != 2 {
-            return Err(Day09Error::InvalidInputFormat);
-        }
-
-        let mut coordinates: [usize; 2] = [0; 2];
-        for (i, coordinate) in parts.iter().enumerate() {
-            coordinates[i] = usize::from_str(coordinate.trim())
-                .map_err(Day09Error::InvalidCoordinate)?;
-        }
-
-        tiles.push(Tile {
-            x: coordinates[0],
-            y: coordinates[1],
-        });
-    }
-
-    Ok(tiles)
-}
-
-pub struct Tile {
-    x: usize,
-    y: usize,
-}
-
-impl Tile {
-    pub fn area(&self, other_corner: &Self) -> usize {
-        ((isize::abs(other_corner.x as isize - self.x as isize) + 1)
-            * (isize::abs(other_corner.y as isize - self.y as isize) + 1))
-            as usize
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_1() {
-        let input = "7,1
-11,1
-11,7
-9,7
-9,5
-2,5
-2,3
-7,3";
-
-        let actual = process(input.to_string()).unwrap();
-        let expected = "50";
-
-        assert_eq!(actual, expected);
-    }
-}
@@ -0,0 +1,38 @@
+use crate::YEAR;
+use common::answer::Answer;
+use common::errors::Error;
+use common::registry::Day;
+use common::solution::{Metadata, Solution};
+
+pub mod errors;
+pub mod model;
+pub mod part1; // 10.12.2025
+pub mod part2; // 10.12.2025
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Model = Vec<model::MachineConfiguration>;
+
+    const METADATA: Metadata = Metadata {
+        year: YEAR.number,
+        day: 10,
+        title: "Factory",
+        date: "10.12.2025",
+        parts: 2,
+    };
+
+    fn parse(input: String) -> Result<Self::Model, Error> {
+        Ok(model::parse(&input)?)
+    }
+
+    fn part1(configurations: &Self::Model) -> Result<Answer, Error> {
+        part1::process(configurations)
+    }
+
+    fn part2(configurations: &Self::Model) -> Result<Answer, Error> {
+        part2::process(configurations)
+    }
+}
+
+inventory::submit! { Day::new::<Day10>() }
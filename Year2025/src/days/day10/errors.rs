@@ -1,3 +1,4 @@
+use common::errors::{Category, DayError};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -18,6 +19,12 @@ pub enum Day10Error {
     #[error("Schematics have different lengths")]
     SchematicSizeMismatch,
 
+    #[error("Button toggles light {0}, but there are only {1}")]
+    ButtonOutOfRange(usize, usize),
+
+    #[error("{0} joltages given for {1} lights")]
+    JoltageCountMismatch(usize, usize),
+
     // Part 2
     #[error("No model found that matches the needed schematic and joltages")]
     NoModelFound,
@@ -34,3 +41,18 @@ pub enum Day10Error {
     #[error("The query was interrupted, timed out or otherwise failed. Machine ID: {0}")]
     UnknownEvaluationResult(usize),
 }
+
+impl DayError for Day10Error {
+    const DAY: u8 = 10;
+
+    fn category(&self) -> Category {
+        match self {
+            Day10Error::NoModelFound
+            | Day10Error::VariableEvaluationFailed
+            | Day10Error::BadResult
+            | Day10Error::Unsatisfiable(_)
+            | Day10Error::UnknownEvaluationResult(_) => Category::Solver,
+            _ => Category::Parse,
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use crate::days::day10::errors::Day10Error;
+use common::diagnostic::{Diagnostic, Locate, Span};
+use std::ops::BitXor;
+use std::str::FromStr;
+
+pub fn parse(input: &str) -> Result<Vec<MachineConfiguration>, Diagnostic<Day10Error>> {
+    let mut configurations = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        let configuration =
+            MachineConfiguration::from_str(line).map_err(|d| d.on_line(index))?;
+        configurations.push(configuration);
+    }
+
+    Ok(configurations)
+}
+
+/// One machine: the needed indicator lights, the buttons and the joltage requirements.
+#[derive(Debug)]
+pub struct MachineConfiguration {
+    pub needed_schematic: Schematic,
+    /// Lights (or counters) toggled by each button.
+    pub schematics: Vec<Schematic>,
+    pub joltages: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Schematic {
+    pub states: Vec<bool>,
+}
+
+impl Schematic {
+    /// Schematic of `size` lights with the ones in `buffer` on. Fails on a light out of
+    /// range.
+    pub fn new(buffer: Vec<usize>, size: usize) -> Result<Self, Day10Error> {
+        let mut states = vec![false; size];
+        for index in buffer {
+            let state = states
+                .get_mut(index)
+                .ok_or(Day10Error::ButtonOutOfRange(index, size))?;
+            *state = true;
+        }
+        Ok(Self { states })
+    }
+
+    fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    pub fn from_states(states: Vec<bool>) -> Self {
+        Self { states }
+    }
+}
+
+impl PartialEq for Schematic {
+    fn eq(&self, other: &Self) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+
+        for i in 0..self.len() {
+            if self.states[i] != other.states[i] {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl BitXor for &Schematic {
+    type Output = Result<Schematic, Day10Error>;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        if self.len() != rhs.len() {
+            return Err(Day10Error::SchematicSizeMismatch);
+        }
+
+        let mut new_state = Vec::new();
+        for i in 0..self.len() {
+            new_state.push(self.states[i] ^ rhs.states[i]);
+        }
+
+        Ok(Schematic::from_states(new_state))
+    }
+}
+
+impl FromStr for MachineConfiguration {
+    type Err = Diagnostic<Day10Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.split_whitespace().collect::<Vec<&str>>();
+
+        let line = || Span::locate(s, s);
+        if parts.len() < 2 {
+            return Err(Day10Error::InvalidInputFormat).at(line);
+        }
+
+        let lights = parts
+            .first()
+            .ok_or(Day10Error::InvalidInputFormat)
+            .at(line)?;
+        let lights = lights.trim_start_matches('[').trim_end_matches(']');
+        let mut needed_states = Vec::new();
+        for (index, symbol) in lights.char_indices() {
+            let state = match symbol {
+                '.' => Ok(false),
+                '#' => Ok(true),
+                _ => Err(Day10Error::InvalidLightIndicator(symbol)),
+            }
+            .at(|| Span::locate(s, &lights[index..index + symbol.len_utf8()]))?;
+            needed_states.push(state);
+        }
+        let needed_schematic = Schematic::from_states(needed_states);
+
+        let joltage_list = parts
+            .last()
+            .ok_or(Day10Error::InvalidInputFormat)
+            .at(line)?;
+        let mut joltages: Vec<usize> = Vec::new();
+        for number in joltage_list
+            .trim_start_matches('{')
+            .trim_end_matches('}')
+            .split(',')
+        {
+            let joltage = number
+                .parse::<usize>()
+                .map_err(Day10Error::InvalidJoltage)
+                .at(|| Span::locate(s, number))?;
+            joltages.push(joltage);
+        }
+        if joltages.len() != needed_schematic.len() {
+            let error =
+                Day10Error::JoltageCountMismatch(joltages.len(), needed_schematic.len());
+            return Err(error).at(|| Span::locate(s, joltage_list));
+        }
+
+        let mut schematics: Vec<Schematic> = Vec::new();
+        // Iterating on line, skipping first element (states) and last (joltages)
+        for schematic in parts.iter().skip(1).take(parts.len() - 2) {
+            let mut buttons = Vec::new();
+            for number in schematic
+                .trim_start_matches('(')
+                .trim_end_matches(')')
+                .split(',')
+            {
+                let button_number = number
+                    .parse::<usize>()
+                    .map_err(Day10Error::InvalidSchematic)
+                    .at(|| Span::locate(s, number))?;
+                buttons.push(button_number);
+            }
+            let schematic = Schematic::new(buttons, needed_schematic.len())
+                .at(|| Span::locate(s, schematic))?;
+            schematics.push(schematic);
+        }
+
+        Ok(Self {
+            needed_schematic,
+            schematics,
+            joltages,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}";
+        assert_eq!(parse(input).unwrap()[0].schematics.len(), 6);
+
+        for (input, columns) in [
+            ("[.##.] (3) (1,4) {3,5,4,7}", 11..16),
+            ("[.##.]", 0..6),
+            ("[.##.] (3) {3,5,4}", 11..18),
+        ] {
+            let lines = format!("[#] (0) {{1}}\n{}", input);
+            let diagnostic = parse(&lines).unwrap_err();
+            assert_eq!(diagnostic.span.line, 2, "{}", input);
+            assert_eq!(diagnostic.span.columns, columns, "{}", input);
+        }
+    }
+}
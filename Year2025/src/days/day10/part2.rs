@@ -1,25 +1,34 @@
 use crate::days::day10::errors::Day10Error;
-use crate::errors::Error;
-use std::str::FromStr;
-use z3::{Optimize, SatResult};
+use crate::days::day10::model::MachineConfiguration;
+use common::answer::Answer;
+use common::cancel;
+use common::errors::Error;
+use z3::{Optimize, Params, SatResult};
 
-// Answer: 17424
-
-pub fn process(input: String) -> Result<String, Error> {
-    let configurations = parse_input(input)?;
+pub fn process(configurations: &[MachineConfiguration]) -> Result<Answer, Error> {
     let sum_of_presses = solve(configurations)?;
 
-    Ok(sum_of_presses.to_string())
+    Ok(sum_of_presses.into())
 }
 
 type Z3Int = z3::ast::Int;
 
-fn solve(configurations: Vec<MachineConfiguration>) -> Result<usize, Day10Error> {
+fn solve(configurations: &[MachineConfiguration]) -> Result<usize, Error> {
     let mut total_presses: usize = 0;
 
     for (machine_id, config) in configurations.iter().enumerate() {
         let optimizer = Optimize::new();
 
+        // z3 doesn't check the cancellation token, so it gets the time left as its own
+        // timeout and gives up with an unknown result. Rounded up, so the token is
+        // cancelled by then.
+        if let Some(remaining) = cancel::remaining()? {
+            let mut params = Params::new();
+            let milliseconds = (remaining.as_millis() + 1).min(u32::MAX as u128) as u32;
+            params.set_u32("timeout", milliseconds);
+            optimizer.set_params(&params);
+        }
+
         // Create variables: one integer variable for each button (schematic)
         // representing how many times we press it.
         let mut button_variables: Vec<Z3Int> = Vec::new();
@@ -74,9 +83,10 @@ fn solve(configurations: Vec<MachineConfiguration>) -> Result<usize, Day10Error>
                 }
                 total_presses += machine_total as usize;
             },
-            SatResult::Unsat => return Err(Day10Error::Unsatisfiable(machine_id)),
+            SatResult::Unsat => return Err(Day10Error::Unsatisfiable(machine_id).into()),
             SatResult::Unknown => {
-                return Err(Day10Error::UnknownEvaluationResult(machine_id));
+                cancel::remaining()?;
+                return Err(Day10Error::UnknownEvaluationResult(machine_id).into());
             },
         }
     }
@@ -84,132 +94,16 @@ fn solve(configurations: Vec<MachineConfiguration>) -> Result<usize, Day10Error>
     Ok(total_presses)
 }
 
-fn parse_input(input: String) -> Result<Vec<MachineConfiguration>, Day10Error> {
-    let lines = input.lines().collect::<Vec<&str>>();
-
-    let mut configurations = Vec::new();
-    for line in lines {
-        let configuration = MachineConfiguration::from_str(line)?;
-        configurations.push(configuration);
-    }
-
-    Ok(configurations)
-}
-
-#[derive(Debug)]
-struct MachineConfiguration {
-    pub _needed_schematic: Schematic,
-    pub schematics: Vec<Schematic>,
-    pub joltages: Vec<usize>,
-}
-
-#[derive(Debug, Clone)]
-struct Schematic {
-    states: Vec<bool>,
-}
-
-impl Schematic {
-    pub fn new(buffer: Vec<usize>, size: usize) -> Self {
-        let mut states = vec![false; size];
-        for index in buffer {
-            states[index] = true;
-        }
-        Self { states }
-    }
-
-    pub fn from_states(states: Vec<bool>) -> Self {
-        Self { states }
-    }
-}
-
-impl FromStr for MachineConfiguration {
-    type Err = Day10Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts = s.split_whitespace().collect::<Vec<&str>>();
-
-        let light_indicators = parts
-            .first()
-            .ok_or(Self::Err::InvalidInputFormat)?
-            .trim()
-            .trim_start_matches('[')
-            .trim_end_matches(']')
-            .chars()
-            .map(|symbol| match symbol {
-                '.' => Ok(false),
-                '#' => Ok(true),
-                _ => Err(Self::Err::InvalidLightIndicator(symbol)),
-            })
-            .collect::<Vec<Result<bool, Self::Err>>>();
-        let mut needed_states = Vec::new();
-        for indicator in light_indicators {
-            needed_states.push(indicator?);
-        }
-        let needed_schematic = Schematic::from_states(needed_states);
-
-        let joltage_results = parts
-            .last()
-            .ok_or(Self::Err::InvalidInputFormat)?
-            .trim()
-            .trim_start_matches('{')
-            .trim_end_matches('}')
-            .split(',')
-            .map(|number| number.parse::<usize>().map_err(Self::Err::InvalidJoltage))
-            .collect::<Vec<Result<usize, Self::Err>>>();
-        let mut joltages: Vec<usize> = Vec::new();
-        for joltage in joltage_results {
-            joltages.push(joltage?);
-        }
-
-        let mut schematics: Vec<Schematic> = Vec::new();
-        // Iterating on line, skipping first element (states) and last (joltages)
-        for schematic in parts.iter().skip(1).take(parts.len() - 2) {
-            let schematic_results = schematic
-                .trim()
-                .trim_start_matches('(')
-                .trim_end_matches(')')
-                .split(',')
-                .map(|number| {
-                    number.parse::<usize>().map_err(Self::Err::InvalidSchematic)
-                })
-                .collect::<Vec<Result<usize, Self::Err>>>();
-            let mut schematic = Vec::new();
-            for button_number in schematic_results {
-                schematic.push(button_number?);
-            }
-            let schematic = Schematic::new(schematic, needed_schematic.states.len());
-            schematics.push(schematic);
-        }
-
-        Ok(Self {
-            _needed_schematic: needed_schematic,
-            schematics,
-            joltages,
-        })
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    #[test]
-    fn test_0() {
-        let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
-[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
-[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
-
-        let actual = process(input.to_string()).unwrap();
-        let expected = "33";
-
-        assert_eq!(actual, expected);
-    }
+    use crate::days::day10::model::parse;
 
     #[test]
     fn test_1() {
         let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}";
 
-        let actual = process(input.to_string()).unwrap();
+        let actual = process(&parse(input).unwrap()).unwrap();
         let expected = "10";
 
         assert_eq!(actual, expected);
@@ -219,7 +113,7 @@ mod tests {
     fn test_2() {
         let input = "[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}";
 
-        let actual = process(input.to_string()).unwrap();
+        let actual = process(&parse(input).unwrap()).unwrap();
         let expected = "12";
 
         assert_eq!(actual, expected);
@@ -229,7 +123,7 @@ mod tests {
     fn test_3() {
         let input = "[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
 
-        let actual = process(input.to_string()).unwrap();
+        let actual = process(&parse(input).unwrap()).unwrap();
         let expected = "11";
 
         assert_eq!(actual, expected);
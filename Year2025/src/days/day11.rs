@@ -0,0 +1,42 @@
+use crate::YEAR;
+use common::answer::Answer;
+use common::errors::Error;
+use common::explore::Command;
+use common::registry::Day;
+use common::solution::{Metadata, Solution};
+
+pub mod commands;
+pub mod errors;
+pub mod model;
+pub mod part1; // 11.12.2025
+pub mod part2; // 11.12.2025
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Model = model::Devices;
+
+    const METADATA: Metadata = Metadata {
+        year: YEAR.number,
+        day: 11,
+        title: "Reactor",
+        date: "11.12.2025",
+        parts: 2,
+    };
+
+    const COMMANDS: &'static [Command<Self::Model>] = commands::COMMANDS;
+
+    fn parse(input: String) -> Result<Self::Model, Error> {
+        Ok(model::parse(&input)?)
+    }
+
+    fn part1(devices: &Self::Model) -> Result<Answer, Error> {
+        part1::process(&devices.graph)
+    }
+
+    fn part2(devices: &Self::Model) -> Result<Answer, Error> {
+        part2::process(&devices.graph)
+    }
+}
+
+inventory::submit! { Day::new::<Day11>() }
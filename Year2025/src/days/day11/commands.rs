@@ -0,0 +1,57 @@
+use crate::days::day11::model::Devices;
+use crate::days::day11::part2::count_paths;
+use common::explore::{Command, CommandError, arguments};
+use std::collections::HashMap;
+
+pub const COMMANDS: &[Command<Devices>] = &[
+    Command {
+        name: "paths",
+        args: "FROM TO",
+        help: "Number of paths between two devices",
+        run: paths,
+    },
+    Command {
+        name: "outputs",
+        args: "DEVICE",
+        help: "Devices the device is connected to",
+        run: outputs,
+    },
+];
+
+fn paths(devices: &Devices, args: &[&str]) -> Result<String, CommandError> {
+    let [from, to] = arguments(args)?;
+    let count = count_paths(
+        id(devices, from)?,
+        id(devices, to)?,
+        &devices.graph,
+        &mut HashMap::new(),
+    );
+
+    Ok(count.to_string())
+}
+
+fn outputs(devices: &Devices, args: &[&str]) -> Result<String, CommandError> {
+    let [device] = arguments(args)?;
+    let outputs = devices
+        .graph
+        .get(&id(devices, device)?)
+        .cloned()
+        .unwrap_or_default();
+
+    let mut names = devices
+        .ids
+        .iter()
+        .filter(|(_, id)| outputs.contains(id))
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<&str>>();
+    names.sort();
+    Ok(names.join(" "))
+}
+
+fn id(devices: &Devices, name: &str) -> Result<usize, CommandError> {
+    devices
+        .ids
+        .get(name)
+        .copied()
+        .ok_or(CommandError::Invalid(format!("Unknown device: {}", name)))
+}
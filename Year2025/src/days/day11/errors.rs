@@ -1,3 +1,4 @@
+use common::errors::DayError;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -5,3 +6,7 @@ pub enum Day11Error {
     #[error("Invalid input format: {0}")]
     InvalidInputFormat(String),
 }
+
+impl DayError for Day11Error {
+    const DAY: u8 = 11;
+}
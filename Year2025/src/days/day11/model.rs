@@ -0,0 +1,87 @@
+use crate::days::day11::errors::Day11Error;
+use common::diagnostic::{Diagnostic, Locate, Span};
+use std::collections::HashMap;
+
+/// Devices and their outputs, by device ID. Devices named in [`identifiers`] have fixed
+/// IDs, the rest are numbered in order of appearance.
+pub type Graph = HashMap<usize, Vec<usize>>;
+
+#[derive(Debug)]
+pub struct Devices {
+    pub graph: Graph,
+    /// Device IDs by name.
+    pub ids: HashMap<String, usize>,
+}
+
+pub mod identifiers {
+    // "you"
+    pub const START: usize = 0;
+    // "out"
+    pub const END: usize = 1;
+    // "dac"
+    pub const DIGITAL_ANALOG_CONVERTER: usize = 2;
+    // "fft"
+    pub const FAST_FOURIER_TRANSFORM: usize = 3;
+    // "svr"
+    pub const SERVER_RACK: usize = 4;
+}
+
+pub fn parse(input: &str) -> Result<Devices, Diagnostic<Day11Error>> {
+    let mut graph_map: Graph = HashMap::new();
+
+    let mut identifiers: HashMap<String, usize> = HashMap::new();
+
+    identifiers.insert("you".to_string(), identifiers::START);
+    identifiers.insert("out".to_string(), identifiers::END);
+    identifiers.insert("dac".to_string(), identifiers::DIGITAL_ANALOG_CONVERTER);
+    identifiers.insert("fft".to_string(), identifiers::FAST_FOURIER_TRANSFORM);
+    identifiers.insert("svr".to_string(), identifiers::SERVER_RACK);
+
+    // Because "you", "out", "dac", "fft" and "svr" are already taken
+    let mut id_counter: usize = 5;
+
+    for line in input.lines() {
+        let parts: Vec<&str> = line.split(":").collect();
+        if parts.len() != 2 {
+            return Err(Day11Error::InvalidInputFormat(line.to_string()))
+                .at(|| Span::locate(input, line));
+        }
+        let device_name = parts[0].trim();
+
+        let outputs = parts[1]
+            .split_whitespace()
+            .map(|s| s.trim())
+            .collect::<Vec<&str>>();
+
+        let device_id =
+            get_unique_device_id(device_name, &mut identifiers, &mut id_counter);
+
+        let output_ids = outputs
+            .iter()
+            .map(|&output_name| {
+                get_unique_device_id(output_name, &mut identifiers, &mut id_counter)
+            })
+            .collect::<Vec<usize>>();
+
+        graph_map.insert(device_id, output_ids);
+    }
+
+    Ok(Devices {
+        graph: graph_map,
+        ids: identifiers,
+    })
+}
+
+fn get_unique_device_id(
+    name: &str, map: &mut HashMap<String, usize>, counter: &mut usize,
+) -> usize {
+    match map.get(name) {
+        Some(id) => *id,
+        None => {
+            let new_id = *counter;
+            map.insert(name.to_string(), new_id);
+            *counter += 1;
+            new_id
+        },
+    }
+}
@@ -1,28 +1,24 @@
-use crate::days::day11::errors::Day11Error;
-use crate::errors::Error;
+use crate::days::day11::model::{Graph, identifiers};
+use common::answer::Answer;
+use common::errors::Error;
 use std::collections::HashMap;
 
-// Answer: 511
-
-pub fn process(input: String) -> Result<String, Error> {
-    let graph_map = parse_input(input)?;
-
+pub fn process(graph_map: &Graph) -> Result<Answer, Error> {
     // Cache to store the number of paths from a specific node to the end.
     // This prevents recalculating paths for nodes we've already visited.
     let mut memo: HashMap<usize, u64> = HashMap::new();
 
-    let count = count_paths(IDENTIFIER_START, &graph_map, &mut memo);
+    let count = count_paths(identifiers::START, graph_map, &mut memo);
 
-    Ok(count.to_string())
+    Ok(count.into())
 }
 
-// Recursive function to find all paths from current_node to IDENTIFIER_END
+// Recursive function to find all paths from current_node to identifiers::END
 fn count_paths(
-    current_node: usize, graph: &HashMap<usize, Vec<usize>>,
-    memo: &mut HashMap<usize, u64>,
+    current_node: usize, graph: &Graph, memo: &mut HashMap<usize, u64>,
 ) -> u64 {
     // Base case: If we reached "out", we found 1 valid path.
-    if current_node == IDENTIFIER_END {
+    if current_node == identifiers::END {
         return 1;
     }
 
@@ -44,83 +40,3 @@ fn count_paths(
     memo.insert(current_node, total_paths);
     total_paths
 }
-
-const IDENTIFIER_START: usize = 0;
-const IDENTIFIER_END: usize = 1;
-
-fn parse_input(input: String) -> Result<HashMap<usize, Vec<usize>>, Day11Error> {
-    let mut graph_map: HashMap<usize, Vec<usize>> = HashMap::new();
-
-    let mut identifiers: HashMap<String, usize> = HashMap::new();
-
-    identifiers.insert("you".to_string(), IDENTIFIER_START);
-    identifiers.insert("out".to_string(), IDENTIFIER_END);
-
-    // Because "you" and "out" are already taken
-    let mut id_counter: usize = 2;
-
-    for line in input.lines() {
-        let parts: Vec<&str> = line.split(":").collect();
-        if parts.len() != 2 {
-            return Err(Day11Error::InvalidInputFormat(line.to_string()));
-        }
-        let device_name = parts[0].trim();
-
-        let outputs = parts[1]
-            .split_whitespace()
-            .map(|s| s.trim())
-            .collect::<Vec<&str>>();
-
-        let device_id =
-            get_unique_device_id(device_name, &mut identifiers, &mut id_counter);
-
-        let output_ids = outputs
-            .iter()
-            .map(|&output_name| {
-                get_unique_device_id(output_name, &mut identifiers, &mut id_counter)
-            })
-            .collect::<Vec<usize>>();
-
-        graph_map.insert(device_id, output_ids);
-    }
-
-    Ok(graph_map)
-}
-
-fn get_unique_device_id(
-    name: &str, map: &mut HashMap<String, usize>, counter: &mut usize,
-) -> usize {
-    match map.get(name) {
-        Some(id) => *id,
-        None => {
-            let new_id = *counter;
-            map.insert(name.to_string(), new_id);
-            *counter += 1;
-            new_id
-        },
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_1() {
-        let input = "aaa: you hhh
-you: bbb ccc
-bbb: ddd eee
-ccc: ddd eee fff
-ddd: ggg
-eee: out
-fff: out
-ggg: out
-hhh: ccc fff iii
-iii: out";
-
-        let actual = process(input.to_string()).unwrap();
-        let expected = "5";
-
-        assert_eq!(actual, expected);
-    }
-}
@@ -1,16 +1,13 @@
-use crate::days::day11::errors::Day11Error;
-use crate::errors::Error;
+use crate::days::day11::model::{Graph, identifiers};
+use common::answer::Answer;
+use common::errors::Error;
 use std::collections::HashMap;
 
-// Answer: 458618114529380
-
-pub fn process(input: String) -> Result<String, Error> {
-    let graph_map = parse_input(input)?;
-
+pub fn process(graph_map: &Graph) -> Result<Answer, Error> {
     // Helper closure to calculate paths between specific start and end nodes
     let calc_segment = |start, end| -> u64 {
         let mut memo = HashMap::new();
-        count_paths(start, end, &graph_map, &mut memo)
+        count_paths(start, end, graph_map, &mut memo)
     };
 
     // Scenario 1: svr -> dac -> fft -> out
@@ -44,11 +41,12 @@ pub fn process(input: String) -> Result<String, Error> {
 
     let total = paths_scenario_1 + paths_scenario_2;
 
-    Ok(total.to_string())
+    Ok(total.into())
 }
 
-fn count_paths(
-    current_node: usize, target_node: usize, graph: &HashMap<usize, Vec<usize>>,
+/// Number of paths from `current_node` to `target_node`. The graph has no cycles.
+pub fn count_paths(
+    current_node: usize, target_node: usize, graph: &Graph,
     memo: &mut HashMap<usize, u64>,
 ) -> u64 {
     // Base case: If we reached the specific target for this segment
@@ -73,99 +71,3 @@ fn count_paths(
     memo.insert(current_node, total_paths);
     total_paths
 }
-
-mod identifiers {
-    // "you"
-    pub const START: usize = 0;
-    // "out"
-    pub const END: usize = 1;
-    // "dac"
-    pub const DIGITAL_ANALOG_CONVERTER: usize = 2;
-    // "fft"
-    pub const FAST_FOURIER_TRANSFORM: usize = 3;
-    // "svr"
-    pub const SERVER_RACK: usize = 4;
-}
-
-fn parse_input(input: String) -> Result<HashMap<usize, Vec<usize>>, Day11Error> {
-    let mut graph_map: HashMap<usize, Vec<usize>> = HashMap::new();
-
-    let mut identifiers: HashMap<String, usize> = HashMap::new();
-
-    identifiers.insert("you".to_string(), identifiers::START);
-    identifiers.insert("out".to_string(), identifiers::END);
-    identifiers.insert("dac".to_string(), identifiers::DIGITAL_ANALOG_CONVERTER);
-    identifiers.insert("fft".to_string(), identifiers::FAST_FOURIER_TRANSFORM);
-    identifiers.insert("svr".to_string(), identifiers::SERVER_RACK);
-
-    // Because "you", "out", "dac", "fft" and "svr" are already taken
-    let mut id_counter: usize = 5;
-
-    for line in input.lines() {
-        let parts: Vec<&str> = line.split(":").collect();
-        if parts.len() != 2 {
-            return Err(Day11Error::InvalidInputFormat(line.to_string()));
-        }
-        let device_name = parts[0].trim();
-
-        let outputs = parts[1]
-            .split_whitespace()
-            .map(|s| s.trim())
-            .collect::<Vec<&str>>();
-
-        let device_id =
-            get_unique_device_id(device_name, &mut identifiers, &mut id_counter);
-
-        let output_ids = outputs
-            .iter()
-            .map(|&output_name| {
-                get_unique_device_id(output_name, &mut identifiers, &mut id_counter)
-            })
-            .collect::<Vec<usize>>();
-
-        graph_map.insert(device_id, output_ids);
-    }
-
-    Ok(graph_map)
-}
-
-fn get_unique_device_id(
-    name: &str, map: &mut HashMap<String, usize>, counter: &mut usize,
-) -> usize {
-    match map.get(name) {
-        Some(id) => *id,
-        None => {
-            let new_id = *counter;
-            map.insert(name.to_string(), new_id);
-            *counter += 1;
-            new_id
-        },
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_1() {
-        let input = "svr: aaa bbb
-aaa: fft
-fft: ccc
-bbb: tty
-tty: ccc
-ccc: ddd eee
-ddd: hub
-hub: fff
-eee: dac
-dac: fff
-fff: ggg hhh
-ggg: out
-hhh: out";
-
-        let actual = process(input.to_string()).unwrap();
-        let expected = "2";
-
-        assert_eq!(actual, expected);
-    }
-}
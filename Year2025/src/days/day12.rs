@@ -0,0 +1,33 @@
+use crate::YEAR;
+use common::answer::Answer;
+use common::errors::Error;
+use common::registry::Day;
+use common::solution::{Metadata, Solution};
+
+pub mod errors;
+pub mod model;
+pub mod part1; // 12.12.2025
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Model = model::Farm;
+
+    const METADATA: Metadata = Metadata {
+        year: YEAR.number,
+        day: 12,
+        title: "Christmas Tree Farm",
+        date: "12.12.2025",
+        parts: 1,
+    };
+
+    fn parse(input: String) -> Result<Self::Model, Error> {
+        Ok(model::parse(&input)?)
+    }
+
+    fn part1(farm: &Self::Model) -> Result<Answer, Error> {
+        part1::process(farm)
+    }
+}
+
+inventory::submit! { Day::new::<Day12>() }
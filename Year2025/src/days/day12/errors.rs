@@ -1,3 +1,4 @@
+use common::errors::{Category, DayError};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -10,4 +11,19 @@ pub enum Day12Error {
 
     #[error("Failed to parse integer")]
     ParseIntError(#[from] std::num::ParseIntError),
+
+    #[error("{0} present counts given for {1} shapes")]
+    RequirementsMismatch(usize, usize),
+}
+
+impl DayError for Day12Error {
+    const DAY: u8 = 12;
+
+    /// A mismatch means the caller of the solver passed the wrong number of counts.
+    fn category(&self) -> Category {
+        match self {
+            Day12Error::RequirementsMismatch(_, _) => Category::Usage,
+            _ => Category::Parse,
+        }
+    }
 }
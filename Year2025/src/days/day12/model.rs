@@ -0,0 +1,91 @@
+use crate::days::day12::errors::Day12Error;
+use common::diagnostic::{Diagnostic, Locate, Span};
+
+/// Present shapes, then the regions under the trees with the presents to fit in them.
+#[derive(Debug)]
+pub struct Farm {
+    pub shapes: Vec<ShapePoints>,
+    /// Width, height and how many presents of each shape have to fit.
+    pub queries: Vec<(usize, usize, Vec<usize>)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Copy, PartialOrd, Ord)]
+pub struct Point {
+    pub r: i32,
+    pub c: i32,
+}
+
+pub type ShapePoints = Vec<Point>;
+
+pub fn parse(input: &str) -> Result<Farm, Diagnostic<Day12Error>> {
+    let mut shapes = Vec::new();
+    let mut queries = Vec::new();
+    let parts: Vec<&str> = input.trim().split("\n\n").collect();
+
+    for block in parts {
+        let first_line = block.lines().next().unwrap_or("");
+
+        if block.contains("x") && block.contains(":") && !first_line.contains('#') {
+            for line in block.lines() {
+                if line.is_empty() {
+                    continue;
+                }
+                let (dim, counts) = line
+                    .split_once(":")
+                    .ok_or(Day12Error::InvalidQueryFormat)
+                    .at(|| Span::locate(input, line))?;
+                let (w_s, h_s) = dim
+                    .split_once("x")
+                    .ok_or(Day12Error::InvalidDimensionFormat)
+                    .at(|| Span::locate(input, dim))?;
+                let w: usize = parse_number(input, w_s.trim())?;
+                let h: usize = parse_number(input, h_s.trim())?;
+                let c: Vec<usize> = counts
+                    .split_whitespace()
+                    .map(|n| parse_number(input, n))
+                    .collect::<Result<_, _>>()?;
+                queries.push((w, h, c));
+            }
+        } else {
+            let mut points = Vec::new();
+            let mut lines = block.lines();
+            let _ = lines.next();
+            for (r, line) in lines.enumerate() {
+                for (c, ch) in line.chars().enumerate() {
+                    if ch == '#' {
+                        points.push(Point {
+                            r: r as i32,
+                            c: c as i32,
+                        });
+                    }
+                }
+            }
+            if !points.is_empty() {
+                normalize(&mut points);
+                shapes.push(points);
+            }
+        }
+    }
+    Ok(Farm { shapes, queries })
+}
+
+fn parse_number(input: &str, number: &str) -> Result<usize, Diagnostic<Day12Error>> {
+    number
+        .parse()
+        .map_err(Day12Error::from)
+        .at(|| Span::locate(input, number))
+}
+
+/// Moves the shape to the top-left corner and sorts its points.
+pub fn normalize(points: &mut ShapePoints) {
+    if points.is_empty() {
+        return;
+    }
+    let min_r = points.iter().map(|p| p.r).min().unwrap_or(0);
+    let min_c = points.iter().map(|p| p.c).min().unwrap_or(0);
+    for p in points.iter_mut() {
+        p.r -= min_r;
+        p.c -= min_c;
+    }
+    points.sort();
+}
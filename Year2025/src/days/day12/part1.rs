@@ -1,10 +1,12 @@
 use crate::days::day12::errors::Day12Error;
-use crate::errors::Error;
+use crate::days::day12::model::{Farm, Point, ShapePoints, normalize};
+use common::answer::Answer;
+use common::cancel::{self, Cancelled};
+use common::errors::Error;
+use common::grid::Grid;
 
-// Answer: 403
-
-pub fn process(input: String) -> Result<String, Error> {
-    let (shapes, queries) = parse_input(&input)?;
+pub fn process(farm: &Farm) -> Result<Answer, Error> {
+    let Farm { shapes, queries } = farm;
 
     // Generate all geometric variations (rotations/flips) for base shapes
     let shape_variations: Vec<Vec<ShapePoints>> =
@@ -12,65 +14,80 @@ pub fn process(input: String) -> Result<String, Error> {
 
     let mut solvable_count = 0;
 
-    for (w, h, requirements) in queries {
-        // Prepare the list of tasks (presents to fit)
-        let mut presents_to_fit = Vec::new();
-        let mut total_presents_area = 0;
-
-        for (shape_id, count) in requirements.iter().enumerate() {
-            let area = shapes[shape_id].len();
-            for _ in 0..*count {
-                presents_to_fit.push(shape_id);
-                total_presents_area += area;
-            }
-        }
-
-        // Optimization: Sort by area (descending). Big pieces first = fail faster.
-        presents_to_fit.sort_by(|a, b| {
-            let size_a = shapes[*a].len();
-            let size_b = shapes[*b].len();
-            size_b.cmp(&size_a).then(a.cmp(b))
-        });
-
-        // Pre-compile shapes into "Linear Offsets" for this specific grid width.
-        let compiled_variations: Vec<Vec<CompiledShape>> = shape_variations
-            .iter()
-            .map(|vars| vars.iter().map(|v| compile_shape(v, w, h)).collect())
-            .collect();
-
-        let mut grid = vec![false; w * h];
-        let total_cells = w * h;
-
-        // Group static context to reduce arguments
-        let context = SolverContext {
-            presents: &presents_to_fit,
-            variations: &compiled_variations,
-        };
-
-        if solve(
-            &context,
-            total_cells,
-            0, // present index
-            &mut grid,
-            0, // last pos (symmetry breaking)
-            total_presents_area,
-        ) {
+    for &(w, h, ref requirements) in queries {
+        if fits_variations(shapes, &shape_variations, w, h, requirements)? {
             solvable_count += 1;
         }
     }
 
-    Ok(solvable_count.to_string())
+    Ok(solvable_count.into())
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Copy, PartialOrd, Ord)]
-struct Point {
-    r: i32,
-    c: i32,
+/// Whether the presents fit into a `w` x `h` region together. `requirements[i]` is the
+/// number of presents of `shapes[i]`, which may be rotated and flipped. Fails if there
+/// isn't one requirement per shape.
+pub fn fits(
+    shapes: &[ShapePoints], w: usize, h: usize, requirements: &[usize],
+) -> Result<bool, Error> {
+    let shape_variations: Vec<Vec<ShapePoints>> =
+        shapes.iter().map(generate_variations).collect();
+
+    fits_variations(shapes, &shape_variations, w, h, requirements)
 }
 
-type ShapePoints = Vec<Point>;
-type ParseResult =
-    Result<(Vec<ShapePoints>, Vec<(usize, usize, Vec<usize>)>), Day12Error>;
+fn fits_variations(
+    shapes: &[ShapePoints], shape_variations: &[Vec<ShapePoints>], w: usize, h: usize,
+    requirements: &[usize],
+) -> Result<bool, Error> {
+    if requirements.len() != shapes.len() {
+        return Err(
+            Day12Error::RequirementsMismatch(requirements.len(), shapes.len()).into(),
+        );
+    }
+
+    // Prepare the list of tasks (presents to fit)
+    let mut presents_to_fit = Vec::new();
+    let mut total_presents_area = 0;
+
+    for (shape_id, count) in requirements.iter().enumerate() {
+        let area = shapes[shape_id].len();
+        for _ in 0..*count {
+            presents_to_fit.push(shape_id);
+            total_presents_area += area;
+        }
+    }
+
+    // Optimization: Sort by area (descending). Big pieces first = fail faster.
+    presents_to_fit.sort_by(|a, b| {
+        let size_a = shapes[*a].len();
+        let size_b = shapes[*b].len();
+        size_b.cmp(&size_a).then(a.cmp(b))
+    });
+
+    // Pre-compile shapes into "Linear Offsets" for this specific grid width.
+    let compiled_variations: Vec<Vec<CompiledShape>> = shape_variations
+        .iter()
+        .map(|vars| vars.iter().map(|v| compile_shape(v, w, h)).collect())
+        .collect();
+
+    let mut grid = Grid::new(h, w, false);
+    let total_cells = w * h;
+
+    // Group static context to reduce arguments
+    let context = SolverContext {
+        presents: &presents_to_fit,
+        variations: &compiled_variations,
+    };
+
+    Ok(solve(
+        &context,
+        total_cells,
+        0, // present index
+        grid.as_mut_slice(),
+        0, // last pos (symmetry breaking)
+        total_presents_area,
+    )?)
+}
 
 // A shape compiled for a specific grid width
 #[derive(Debug, Clone)]
@@ -90,15 +107,17 @@ struct SolverContext<'a> {
 fn solve(
     ctx: &SolverContext, empty_cells: usize, present_idx: usize, grid: &mut [bool],
     last_pos_index: usize, required_area: usize,
-) -> bool {
+) -> Result<bool, Cancelled> {
+    cancel::check()?;
+
     // Base Case: Success
     if present_idx == ctx.presents.len() {
-        return true;
+        return Ok(true);
     }
 
     // Pruning: Not enough space left physically
     if empty_cells < required_area {
-        return false;
+        return Ok(false);
     }
 
     let shape_id = ctx.presents[present_idx];
@@ -150,8 +169,8 @@ fn solve(
                     grid,
                     anchor, // Pass current anchor for symmetry constraint
                     required_area - current_piece_area,
-                ) {
-                    return true;
+                )? {
+                    return Ok(true);
                 }
 
                 // Backtrack (Unplace)
@@ -162,7 +181,7 @@ fn solve(
         }
     }
 
-    false
+    Ok(false)
 }
 
 fn compile_shape(points: &ShapePoints, grid_w: usize, grid_h: usize) -> CompiledShape {
@@ -194,68 +213,6 @@ fn compile_shape(points: &ShapePoints, grid_w: usize, grid_h: usize) -> Compiled
     }
 }
 
-fn parse_input(input: &str) -> ParseResult {
-    let mut shapes = Vec::new();
-    let mut queries = Vec::new();
-    let parts: Vec<&str> = input.trim().split("\n\n").collect();
-
-    for block in parts {
-        let first_line = block.lines().next().unwrap_or("");
-
-        if block.contains("x") && block.contains(":") && !first_line.contains('#') {
-            for line in block.lines() {
-                if line.is_empty() {
-                    continue;
-                }
-                let (dim, counts) =
-                    line.split_once(":").ok_or(Day12Error::InvalidQueryFormat)?;
-                let (w_s, h_s) = dim
-                    .split_once("x")
-                    .ok_or(Day12Error::InvalidDimensionFormat)?;
-                let w: usize = w_s.trim().parse()?;
-                let h: usize = h_s.trim().parse()?;
-                let c: Vec<usize> = counts
-                    .split_whitespace()
-                    .map(|n| n.parse::<usize>())
-                    .collect::<Result<_, _>>()?;
-                queries.push((w, h, c));
-            }
-        } else {
-            let mut points = Vec::new();
-            let mut lines = block.lines();
-            let _ = lines.next();
-            for (r, line) in lines.enumerate() {
-                for (c, ch) in line.chars().enumerate() {
-                    if ch == '#' {
-                        points.push(Point {
-                            r: r as i32,
-                            c: c as i32,
-                        });
-                    }
-                }
-            }
-            if !points.is_empty() {
-                normalize(&mut points);
-                shapes.push(points);
-            }
-        }
-    }
-    Ok((shapes, queries))
-}
-
-fn normalize(points: &mut ShapePoints) {
-    if points.is_empty() {
-        return;
-    }
-    let min_r = points.iter().map(|p| p.r).min().unwrap_or(0);
-    let min_c = points.iter().map(|p| p.c).min().unwrap_or(0);
-    for p in points.iter_mut() {
-        p.r -= min_r;
-        p.c -= min_c;
-    }
-    points.sort();
-}
-
 fn rotate(shape: &ShapePoints) -> ShapePoints {
     let mut s: ShapePoints = shape.iter().map(|p| Point { r: p.c, c: -p.r }).collect();
     normalize(&mut s);
@@ -281,50 +238,3 @@ fn generate_variations(base: &ShapePoints) -> Vec<ShapePoints> {
     vars.dedup();
     vars
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_1() {
-        let input = "0:
-###
-##.
-##.
-
-1:
-###
-##.
-.##
-
-2:
-.##
-###
-##.
-
-3:
-##.
-###
-##.
-
-4:
-###
-#..
-###
-
-5:
-###
-.#.
-###
-
-4x4: 0 0 0 0 2 0
-12x5: 1 0 1 0 2 2
-12x5: 1 0 1 0 3 2";
-
-        let actual = process(input.to_string()).unwrap();
-        let expected = "2";
-
-        assert_eq!(actual, expected);
-    }
-}
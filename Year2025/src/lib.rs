@@ -0,0 +1,13 @@
+//! Solutions of 2025. Each day registers itself in [`YEAR`], and exposes its input model
+//! (`days::dayNN::model`) and the solver of every part (`days::dayNN::partN`).
+
+use common::registry::Year;
+
+pub mod days;
+
+pub const YEAR: Year = Year {
+    number: 2025,
+    calendar: 1..=12,
+    inputs: concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"),
+    sources: concat!(env!("CARGO_MANIFEST_DIR"), "/src"),
+};
@@ -1,5 +1,6 @@
 #!/usr/bin/env sh
 
-FILE="../target/debug/Year2025"
-cargo build --bin Year2025
-$FILE --day 1 --part 1
\ No newline at end of file
+DIR="$(dirname "$0")"
+FILE="$DIR/../target/debug/aoc"
+cargo build --bin aoc --manifest-path "$DIR/../Cargo.toml"
+AOC_INPUT_DIR="$DIR/inputs" $FILE --year 2025 --day 1 --part 1
@@ -0,0 +1,66 @@
+use common::errors::Category;
+use common::io;
+use year2025::YEAR;
+use year2025::days::{day05, day12};
+
+#[test]
+fn test_registry() {
+    let days = YEAR.days();
+
+    assert_eq!(days.len(), YEAR.calendar.len());
+    assert_eq!(days[4].metadata.title, "Cafeteria");
+}
+
+#[test]
+fn test_parts() {
+    let input = io::read_example_file(&YEAR, 5, 1).unwrap();
+    let inventory = day05::model::parse(&input).unwrap();
+
+    assert_eq!(inventory.ids.len(), 6);
+    assert_eq!(day05::part1::process(&inventory).unwrap(), "3");
+    assert_eq!(day05::part2::process(&inventory).unwrap(), "14");
+}
+
+#[test]
+fn test_packing_solver() {
+    let input = io::read_example_file(&YEAR, 12, 1).unwrap();
+    let farm = day12::model::parse(&input).unwrap();
+
+    let fits = farm
+        .queries
+        .iter()
+        .map(|(w, h, requirements)| {
+            day12::part1::fits(&farm.shapes, *w, *h, requirements)
+        })
+        .collect::<Result<Vec<bool>, _>>()
+        .unwrap();
+
+    assert_eq!(fits, vec![true, true, false]);
+}
+
+#[test]
+fn test_packing_solver_mismatch() {
+    let input = io::read_example_file(&YEAR, 12, 1).unwrap();
+    let farm = day12::model::parse(&input).unwrap();
+
+    let err = day12::part1::fits(&farm.shapes, 4, 4, &[1]).unwrap_err();
+    assert_eq!(err.category(), Category::Usage);
+    assert!(
+        err.to_string()
+            .contains("1 present counts given for 6 shapes")
+    );
+}
+
+#[test]
+fn test_run_through_registry() {
+    let input = io::read_example_file(&YEAR, 5, 1).unwrap();
+    let day = YEAR.find(5, 2).unwrap();
+
+    let runs = day.execute(input, &[1, 2], None).outcome.unwrap();
+    let answers = runs
+        .iter()
+        .map(|run| run.answer.as_ref().unwrap().to_string())
+        .collect::<Vec<String>>();
+
+    assert_eq!(answers, ["3", "14"]);
+}
@@ -0,0 +1,289 @@
+use crate::runner::{format_duration, table};
+use common::errors::Error;
+use common::io;
+use common::registry::{Day, Execution};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Solve,
+}
+
+/// Timings of one stage over all measured iterations.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub stage: Stage,
+    /// `None` for parsing, which is shared by the parts.
+    pub part: Option<u8>,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+}
+
+/// Saved results of a benchmark, compared against later runs.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Baseline {
+    pub year: u16,
+    pub day: u8,
+    pub iterations: u32,
+    pub measurements: Vec<Measurement>,
+}
+
+impl Measurement {
+    fn new(stage: Stage, part: Option<u8>, samples: &mut [Duration]) -> Self {
+        samples.sort();
+        let nanos = samples
+            .iter()
+            .map(|sample| sample.as_nanos() as f64)
+            .collect::<Vec<f64>>();
+
+        let count = nanos.len().max(1) as f64;
+        let mean = nanos.iter().sum::<f64>() / count;
+        let variance = match nanos.len() {
+            0 | 1 => 0.0,
+            len => {
+                nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (len - 1) as f64
+            },
+        };
+        let median = match nanos.len() {
+            0 => 0.0,
+            len if len % 2 == 0 => (nanos[len / 2 - 1] + nanos[len / 2]) / 2.0,
+            len => nanos[len / 2],
+        };
+
+        Self {
+            stage,
+            part,
+            min_ns: nanos.first().copied().unwrap_or_default() as u64,
+            median_ns: median as u64,
+            mean_ns: mean as u64,
+            stddev_ns: variance.sqrt() as u64,
+        }
+    }
+}
+
+/// Runs the parts `warmup` times unmeasured, then `iterations` times measured.
+pub fn run(
+    day: &Day, input: &str, parts: &[u8], warmup: u32, iterations: u32,
+) -> Result<Vec<Measurement>, Error> {
+    for _ in 0..warmup {
+        check(day.execute(input.to_string(), parts, None))?;
+    }
+
+    let mut parse_samples = Vec::with_capacity(iterations as usize);
+    let mut solve_samples = vec![Vec::with_capacity(iterations as usize); parts.len()];
+    for _ in 0..iterations {
+        let (parse_time, solve_times) =
+            check(day.execute(input.to_string(), parts, None))?;
+        parse_samples.push(parse_time);
+        for (samples, time) in solve_samples.iter_mut().zip(solve_times) {
+            samples.push(time);
+        }
+    }
+
+    let mut measurements = vec![Measurement::new(Stage::Parse, None, &mut parse_samples)];
+    for (part, samples) in parts.iter().zip(solve_samples.iter_mut()) {
+        measurements.push(Measurement::new(Stage::Solve, Some(*part), samples));
+    }
+
+    Ok(measurements)
+}
+
+/// Parse time and solve time of every part, or the first error.
+fn check(execution: Execution) -> Result<(Duration, Vec<Duration>), Error> {
+    let mut solve_times = Vec::new();
+    for run in execution.outcome? {
+        run.answer?;
+        solve_times.push(run.solve_time);
+    }
+
+    Ok((execution.parse_time, solve_times))
+}
+
+pub fn report(measurements: &[Measurement], input_len: usize) -> String {
+    const HEADER: [&str; 7] = [
+        "Stage",
+        "Part",
+        "Min",
+        "Median",
+        "Mean",
+        "Std dev",
+        "Throughput",
+    ];
+
+    let rows = measurements
+        .iter()
+        .map(|measurement| {
+            [
+                stage_name(measurement.stage).to_string(),
+                part_name(measurement.part),
+                nanos(measurement.min_ns),
+                nanos(measurement.median_ns),
+                nanos(measurement.mean_ns),
+                nanos(measurement.stddev_ns),
+                throughput(measurement.median_ns, input_len),
+            ]
+        })
+        .collect::<Vec<[String; 7]>>();
+
+    let mut lines = table(HEADER, &rows, &[0]);
+    let total_ns = measurements
+        .iter()
+        .map(|measurement| measurement.mean_ns)
+        .sum();
+    lines.push(String::new());
+    lines.push(format!(
+        "Mean per run: {}. Runs per second: {:.1}",
+        nanos(total_ns),
+        1e9 / total_ns.max(1) as f64
+    ));
+
+    lines.join("\n")
+}
+
+/// A measurement next to the matching one of the baseline, if it has one.
+pub struct Comparison<'a> {
+    pub current: &'a Measurement,
+    pub baseline: Option<&'a Measurement>,
+}
+
+impl Comparison<'_> {
+    /// Change of the median against the baseline, in percent.
+    pub fn change(&self) -> Option<f64> {
+        let baseline = self.baseline?.median_ns.max(1) as f64;
+        Some((self.current.median_ns as f64 - baseline) / baseline * 100.0)
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+pub fn compare<'a>(
+    current: &'a [Measurement], baseline: &'a Baseline,
+) -> Vec<Comparison<'a>> {
+    current
+        .iter()
+        .map(|measurement| Comparison {
+            current: measurement,
+            baseline: baseline.measurements.iter().find(|saved| {
+                saved.stage == measurement.stage && saved.part == measurement.part
+            }),
+        })
+        .collect()
+}
+
+pub fn comparison_report(comparisons: &[Comparison], threshold: f64) -> String {
+    const HEADER: [&str; 6] =
+        ["Stage", "Part", "Baseline", "Current", "Change", "Status"];
+
+    let rows = comparisons
+        .iter()
+        .map(|comparison| {
+            let status = match comparison.change() {
+                None => "NEW",
+                Some(change) if change > threshold => "REGRESSED",
+                Some(change) if change < -threshold => "IMPROVED",
+                Some(_) => "OK",
+            };
+
+            [
+                stage_name(comparison.current.stage).to_string(),
+                part_name(comparison.current.part),
+                comparison
+                    .baseline
+                    .map(|baseline| nanos(baseline.median_ns))
+                    .unwrap_or("-".to_string()),
+                nanos(comparison.current.median_ns),
+                comparison
+                    .change()
+                    .map(|change| format!("{:+.1}%", change))
+                    .unwrap_or("-".to_string()),
+                status.to_string(),
+            ]
+        })
+        .collect::<Vec<[String; 6]>>();
+
+    table(HEADER, &rows, &[0, 5]).join("\n")
+}
+
+pub fn load_baseline(path: &Path) -> Result<Baseline, Error> {
+    let content = io::read_file(path)?;
+    serde_json::from_str(&content)
+        .map_err(|err| Error::InvalidBaseline(path.to_path_buf(), err.to_string()))
+}
+
+pub fn save_baseline(path: &Path, baseline: &Baseline) -> Result<(), Error> {
+    let content = serde_json::to_string_pretty(baseline)
+        .map_err(|err| Error::InvalidBaseline(path.to_path_buf(), err.to_string()))?;
+    Ok(io::write_file(path, &content)?)
+}
+
+fn stage_name(stage: Stage) -> &'static str {
+    match stage {
+        Stage::Parse => "Parse",
+        Stage::Solve => "Solve",
+    }
+}
+
+fn part_name(part: Option<u8>) -> String {
+    part.map(|part| part.to_string()).unwrap_or("-".to_string())
+}
+
+fn nanos(nanos: u64) -> String {
+    format_duration(Duration::from_nanos(nanos))
+}
+
+/// Input processed per second, based on the median.
+fn throughput(median_ns: u64, input_len: usize) -> String {
+    let seconds = median_ns.max(1) as f64 / 1e9;
+    format!("{:.2} MB/s", input_len as f64 / seconds / 1e6)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measurement() {
+        let mut samples = [4, 1, 3, 2].map(Duration::from_nanos);
+
+        let measurement = Measurement::new(Stage::Solve, Some(1), &mut samples);
+
+        assert_eq!(measurement.min_ns, 1);
+        assert_eq!(measurement.median_ns, 2);
+        assert_eq!(measurement.mean_ns, 2);
+        assert_eq!(measurement.stddev_ns, 1);
+    }
+
+    #[test]
+    fn test_regression() {
+        let measurement = |median_ns| Measurement {
+            stage: Stage::Parse,
+            part: None,
+            min_ns: median_ns,
+            median_ns,
+            mean_ns: median_ns,
+            stddev_ns: 0,
+        };
+        let baseline = Baseline {
+            year: 2025,
+            day: 1,
+            iterations: 1,
+            measurements: vec![measurement(100)],
+        };
+
+        let slower = [measurement(110)];
+        let comparisons = compare(&slower, &baseline);
+        assert!(comparisons[0].is_regression(5.0));
+        assert!(!comparisons[0].is_regression(15.0));
+
+        let faster = [measurement(90)];
+        assert!(!compare(&faster, &baseline)[0].is_regression(5.0));
+    }
+}
@@ -0,0 +1,236 @@
+use crate::client::{BASE_URL_VARIABLE, DEFAULT_BASE_URL};
+use crate::output::Format;
+use clap::{Parser, Subcommand};
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const EXIT_CODES: &str = "\
+Exit codes:
+  0  Success
+  1  Wrong answer
+  2  Usage error: invalid year, day, part or arguments
+  3  IO error: input or data file can't be read or downloaded
+  4  Parse error: input or data file is malformed
+  5  Solver error: no solution found for a valid input
+  6  Day or part is not implemented
+  7  Benchmark is slower than its baseline
+  8  Part timed out
+  9  Submission rate limited: the website asks to wait before the next answer";
+
+#[derive(Parser)]
+#[command(after_help = EXIT_CODES, subcommand_negates_reqs = true)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    /// The year to run. Defaults to the latest one
+    #[arg(short, long, global = true)]
+    pub year: Option<u16>,
+    /// The day to run
+    #[arg(short, long, required_unless_present_any = ["list", "all", "days", "verify"])]
+    pub day: Option<u8>,
+    /// Part 1 or 2?
+    #[arg(short, long, required_unless_present_any = ["list", "all", "days", "verify"])]
+    pub part: Option<u8>,
+    /// Input file to use instead of `inputs/dayNN.txt`. Use `-` to read from stdin
+    #[arg(short, long)]
+    pub input: Option<PathBuf>,
+    /// Run on `inputs/examples/dayNN-K.txt` and check the expected answer, if recorded
+    #[arg(short, long, conflicts_with = "input")]
+    pub example: Option<u8>,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t)]
+    pub format: Format,
+    /// List registered days
+    #[arg(short, long)]
+    pub list: bool,
+    /// Run every registered part and print a summary table
+    #[arg(short, long, conflicts_with_all = ["day", "part", "days", "input", "example"])]
+    pub all: bool,
+    /// Run every registered part of the days in range, e.g. `3..=9`
+    #[arg(long, value_parser = parse_days, conflicts_with_all = ["day", "part", "input", "example"])]
+    pub days: Option<RangeInclusive<u8>>,
+    /// Threads for running several days at once. Defaults to the number of CPUs. Timings
+    /// are only isolated with `--jobs 1`: with more, days running together share the CPUs
+    #[arg(short, long)]
+    pub jobs: Option<NonZeroUsize>,
+    /// Rebuild and re-run the part on its input and examples whenever the day's sources,
+    /// input or examples change
+    #[arg(short, long, requires_all = ["day", "part"], conflicts_with_all = ["input", "example", "format"])]
+    pub watch: bool,
+    /// Abort parts running longer than this many seconds, e.g. `2.5`
+    #[arg(short, long, value_parser = parse_timeout)]
+    pub timeout: Option<Duration>,
+    /// Set a value a part reads instead of the puzzle's, e.g. `k=20` for the batteries
+    /// per bank of day 3. Repeat to set several
+    #[arg(long, value_name = "NAME=VALUE", value_parser = parse_param, conflicts_with = "verify")]
+    pub param: Vec<(String, String)>,
+    /// Check answers of every registered part (or of `--days`) against the recorded ones
+    #[arg(long, conflicts_with_all = ["day", "part", "input", "example"])]
+    pub verify: bool,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Generate the module skeleton of a new day, register it and create its input file
+    NewDay {
+        /// The day to generate
+        day: u8,
+        /// Puzzle title
+        #[arg(short, long, default_value = "Untitled")]
+        title: String,
+    },
+    /// Run a day repeatedly and report timing statistics of parsing and solving
+    Bench {
+        /// The day to benchmark
+        #[arg(short, long)]
+        day: u8,
+        /// Part to benchmark. All solved parts by default
+        #[arg(short, long)]
+        part: Option<u8>,
+        /// Input file to use instead of `inputs/dayNN.txt`. Use `-` to read from stdin
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Measured runs
+        #[arg(short = 'n', long, default_value_t = 100, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+        /// Runs before measuring
+        #[arg(short, long, default_value_t = 10)]
+        warmup: u32,
+        /// Save the results as a baseline file
+        #[arg(long)]
+        save_baseline: Option<PathBuf>,
+        /// Compare the results against a baseline file
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Slowdown of the median, in percent, reported as a regression
+        #[arg(long, default_value_t = 5.0)]
+        threshold: f64,
+    },
+    /// Load a day's input into its parsed model and query it at an interactive prompt
+    Explore {
+        /// The day to explore
+        day: u8,
+        /// Input file to use instead of `inputs/dayNN.txt`. Use `-` to read from stdin
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Load `inputs/examples/dayNN-K.txt` instead of the input
+        #[arg(short, long, conflicts_with = "input")]
+        example: Option<u8>,
+        /// Run the command, e.g. `-c "joltage 12"`, and exit instead of prompting.
+        /// Repeat to run several
+        #[arg(short, long)]
+        command: Vec<String>,
+    },
+    /// Download the day's input into the inputs directory, unless it's already there.
+    /// Needs the session token in `AOC_SESSION` or `~/.config/aoc/session`
+    Fetch {
+        /// The day to download
+        day: u8,
+        /// Website to download from, e.g. a local stub server
+        #[arg(long, env = BASE_URL_VARIABLE, default_value = DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Download the puzzle description as markdown next to the day's module and save its
+    /// example blocks as example inputs
+    Puzzle {
+        /// The day to download
+        day: u8,
+        /// Print the downloaded description instead of downloading it
+        #[arg(long)]
+        offline: bool,
+        /// Website to download from, e.g. a local stub server
+        #[arg(long, env = BASE_URL_VARIABLE, default_value = DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Submit the answer of a part. Answers known to be wrong from earlier attempts are
+    /// refused, correct ones are recorded for `--verify`
+    Submit {
+        /// The day to submit
+        #[arg(short, long)]
+        day: u8,
+        /// The part to submit
+        #[arg(short, long)]
+        part: u8,
+        /// Answer to submit. Computed from the day's input by default
+        #[arg(short, long)]
+        answer: Option<String>,
+        /// Website to submit to, e.g. a local stub server
+        #[arg(long, env = BASE_URL_VARIABLE, default_value = DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+}
+
+/// Accepts `N`, `A..B` and `A..=B`.
+fn parse_days(value: &str) -> Result<RangeInclusive<u8>, String> {
+    let parse = |number: &str| {
+        number
+            .trim()
+            .parse::<u8>()
+            .map_err(|err| format!("Invalid day '{}': {}", number, err))
+    };
+
+    if let Some((start, end)) = value.split_once("..=") {
+        Ok(parse(start)?..=parse(end)?)
+    } else if let Some((start, end)) = value.split_once("..") {
+        let end = parse(end)?
+            .checked_sub(1)
+            .ok_or(format!("Empty range: {}", value))?;
+        Ok(parse(start)?..=end)
+    } else {
+        let day = parse(value)?;
+        Ok(day..=day)
+    }
+}
+
+fn parse_param(value: &str) -> Result<(String, String), String> {
+    match value.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.trim().to_string()))
+        },
+        _ => Err(format!(
+            "Invalid parameter '{}', expected NAME=VALUE",
+            value
+        )),
+    }
+}
+
+fn parse_timeout(value: &str) -> Result<Duration, String> {
+    value
+        .parse::<f64>()
+        .map_err(|err| err.to_string())
+        .and_then(|seconds| {
+            Duration::try_from_secs_f64(seconds).map_err(|err| err.to_string())
+        })
+        .map_err(|err| format!("Invalid timeout '{}': {}", value, err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("3..=9"), Ok(3..=9));
+        assert_eq!(parse_days("3..9"), Ok(3..=8));
+        assert_eq!(parse_days("5"), Ok(5..=5));
+        assert!(parse_days("a..=9").is_err());
+        assert!(parse_days("1..0").is_err());
+    }
+
+    #[test]
+    fn test_parse_param() {
+        assert_eq!(parse_param("k=20"), Ok(("k".into(), "20".into())));
+        assert_eq!(parse_param("k = "), Ok(("k".into(), "".into())));
+        assert!(parse_param("k").is_err());
+        assert!(parse_param("=20").is_err());
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        assert_eq!(parse_timeout("2.5"), Ok(Duration::from_millis(2500)));
+        assert!(parse_timeout("-1").is_err());
+        assert!(parse_timeout("soon").is_err());
+    }
+}
@@ -0,0 +1,109 @@
+use common::errors::Error;
+use std::path::PathBuf;
+
+/// Environment variable with the session cookie of the Advent of Code website.
+pub const SESSION_VARIABLE: &str = "AOC_SESSION";
+
+/// Environment variable that overrides the website, e.g. with a local stub server.
+pub const BASE_URL_VARIABLE: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the tool to the website, as its maintainers ask automated clients to.
+const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_REPOSITORY"),
+    " by ",
+    env!("CARGO_PKG_AUTHORS")
+);
+
+/// Authenticated client of the Advent of Code website.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: String) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+        }
+    }
+
+    /// Client of `base_url` with the session token from the environment or config file.
+    pub fn connect(base_url: &str) -> Result<Self, Error> {
+        Ok(Self::new(base_url, session()?))
+    }
+
+    /// Body of the page at `path`, e.g. `/2025/day/1/input`.
+    pub fn get(&self, path: &str) -> Result<String, Error> {
+        let url = format!("{}{}", self.base_url, path);
+        let failed = |err: ureq::Error| Error::Http(url.clone(), describe(err));
+
+        self.agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .call()
+            .map_err(failed)?
+            .body_mut()
+            .read_to_string()
+            .map_err(failed)
+    }
+
+    /// Posts the form to `path` and returns the body of the response.
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, Error> {
+        let url = format!("{}{}", self.base_url, path);
+        let failed = |err: ureq::Error| Error::Http(url.clone(), describe(err));
+
+        self.agent
+            .post(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .send_form(form.iter().copied())
+            .map_err(failed)?
+            .body_mut()
+            .read_to_string()
+            .map_err(failed)
+    }
+}
+
+fn describe(err: ureq::Error) -> String {
+    match err {
+        ureq::Error::StatusCode(404) => "not found, the puzzle may be locked".to_string(),
+        ureq::Error::StatusCode(400 | 401 | 403) => {
+            "access denied, the session token may have expired".to_string()
+        },
+        err => err.to_string(),
+    }
+}
+
+/// `$XDG_CONFIG_HOME/aoc/session`, or `~/.config/aoc/session` without it.
+fn session_file() -> PathBuf {
+    let config = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config"))
+        })
+        .unwrap_or_default();
+    config.join("aoc").join("session")
+}
+
+/// Session token from `AOC_SESSION`, falling back to the session file.
+fn session() -> Result<String, Error> {
+    let file = session_file();
+    let token = match std::env::var(SESSION_VARIABLE) {
+        Ok(token) => token,
+        Err(_) => std::fs::read_to_string(&file).unwrap_or_default(),
+    };
+
+    match token.trim() {
+        "" => Err(Error::MissingSession(file)),
+        token => Ok(token.to_string()),
+    }
+}
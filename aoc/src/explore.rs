@@ -0,0 +1,259 @@
+use crate::runner::format_duration;
+use common::errors::Error;
+use common::explore::{CommandError, Explore, Usage};
+use common::registry::Day;
+use std::io::{BufRead, Write};
+use std::time::Instant;
+
+/// Commands every day has, besides its own.
+const BUILTIN: [Usage; 3] = [
+    Usage {
+        name: "part",
+        args: "N",
+        help: "Solves the part on the loaded input",
+    },
+    Usage {
+        name: "help",
+        args: "",
+        help: "Lists the commands",
+    },
+    Usage {
+        name: "quit",
+        args: "",
+        help: "Leaves the prompt, as does Ctrl-D",
+    },
+];
+
+/// Reply to a line of input.
+#[derive(Debug)]
+enum Reply {
+    Blank,
+    Quit,
+    Text(String),
+    Failed(Error),
+}
+
+/// Parses the input once and answers commands about the model until `quit` or the end
+/// of stdin. With `commands` given, answers those instead of prompting.
+pub fn explore(day: &Day, input: String, commands: &[String]) -> Result<(), Error> {
+    let explorer = day.explore(input)?;
+    if !commands.is_empty() {
+        return run_commands(explorer.as_ref(), commands, |text| println!("{}", text));
+    }
+
+    let prompt = format!("day{:02}> ", day.metadata.day);
+    println!("--- Day {}: {} ---", day.metadata.day, day.metadata.title);
+    println!("Type `help` for commands");
+
+    let mut lines = std::io::stdin().lock().lines();
+    loop {
+        print!("{}", prompt);
+        // The prompt is cosmetic, a failed flush only loses it.
+        let _ = std::io::stdout().flush();
+
+        let Some(Ok(line)) = lines.next() else {
+            println!();
+            return Ok(());
+        };
+        match dispatch(explorer.as_ref(), &line) {
+            Reply::Blank => {},
+            Reply::Quit => return Ok(()),
+            Reply::Text(text) => println!("{}", text),
+            Reply::Failed(err) => println!("Error: {}", err),
+        }
+    }
+}
+
+/// Answers the commands in order and stops at the first failing one. Commands after
+/// `quit` are an error rather than silently skipped.
+fn run_commands(
+    explorer: &dyn Explore, commands: &[String], mut print: impl FnMut(String),
+) -> Result<(), Error> {
+    for (index, line) in commands.iter().enumerate() {
+        match dispatch(explorer, line) {
+            Reply::Blank => {},
+            Reply::Quit if index + 1 < commands.len() => {
+                return Err(Error::InvalidCommand(format!(
+                    "Commands after `quit` are not run: {}",
+                    commands[index + 1..].join("; ")
+                )));
+            },
+            Reply::Quit => {},
+            Reply::Text(text) => print(text),
+            Reply::Failed(err) => return Err(err),
+        }
+    }
+    Ok(())
+}
+
+fn dispatch(explorer: &dyn Explore, line: &str) -> Reply {
+    let words = line.split_whitespace().collect::<Vec<&str>>();
+    let Some((&name, args)) = words.split_first() else {
+        return Reply::Blank;
+    };
+
+    let reply = match name {
+        "quit" | "exit" => return Reply::Quit,
+        "help" => Ok(help(&explorer.commands())),
+        "part" => match args {
+            [part] => match part.parse::<u8>() {
+                Ok(part) => solve(explorer, part),
+                Err(_) => Err(Error::InvalidCommand(format!("Invalid part: {}", part))),
+            },
+            _ => Err(usage(&BUILTIN[0])),
+        },
+        name => match explorer.run(name, args) {
+            Some(Ok(reply)) => Ok(reply),
+            Some(Err(CommandError::Usage)) => {
+                let commands = explorer.commands();
+                let command = commands.iter().find(|command| command.name == name);
+                Err(command
+                    .map(usage)
+                    .unwrap_or(Error::InvalidCommand(name.to_string())))
+            },
+            Some(Err(CommandError::Invalid(message))) => {
+                Err(Error::InvalidCommand(message))
+            },
+            Some(Err(CommandError::Failed(err))) => Err(err),
+            None => Err(Error::InvalidCommand(format!(
+                "Unknown command `{}`. Type `help` for commands",
+                name
+            ))),
+        },
+    };
+    match reply {
+        Ok(text) => Reply::Text(text),
+        Err(err) => Reply::Failed(err),
+    }
+}
+
+fn solve(explorer: &dyn Explore, part: u8) -> Result<String, Error> {
+    let start = Instant::now();
+    let answer = explorer.solve(part)?;
+    Ok(format!("{} ({})", answer, format_duration(start.elapsed())))
+}
+
+fn usage(command: &Usage) -> Error {
+    Error::InvalidCommand(format!("Wrong arguments. Usage: {}", signature(command)))
+}
+
+fn signature(command: &Usage) -> String {
+    format!("{} {}", command.name, command.args)
+        .trim_end()
+        .to_string()
+}
+
+fn help(commands: &[Usage]) -> String {
+    let commands = commands.iter().chain(&BUILTIN).collect::<Vec<&Usage>>();
+    let width = commands
+        .iter()
+        .map(|command| signature(command).len())
+        .max()
+        .unwrap_or_default();
+
+    commands
+        .iter()
+        .map(|command| format!("  {:width$}  {}", signature(command), command.help))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::answer::Answer;
+    use common::errors::Category;
+
+    struct Echo;
+
+    impl Explore for Echo {
+        fn commands(&self) -> Vec<Usage> {
+            vec![Usage {
+                name: "echo",
+                args: "WORD",
+                help: "Repeats the word",
+            }]
+        }
+
+        fn run(&self, name: &str, args: &[&str]) -> Option<Result<String, CommandError>> {
+            match (name, args) {
+                ("echo", [word]) => Some(Ok(word.to_string())),
+                ("echo", _) => Some(Err(CommandError::Usage)),
+                _ => None,
+            }
+        }
+
+        fn solve(&self, part: u8) -> Result<Answer, Error> {
+            Err(Error::NotImplemented(1, part))
+        }
+    }
+
+    #[test]
+    fn test_dispatch() {
+        let reply = |line: &str| match dispatch(&Echo, line) {
+            Reply::Text(text) => Some(text),
+            Reply::Failed(err) => Some(format!("Error: {}", err)),
+            Reply::Blank | Reply::Quit => None,
+        };
+
+        assert_eq!(reply("echo hi"), Some("hi".to_string()));
+        assert_eq!(
+            reply("echo"),
+            Some("Error: Wrong arguments. Usage: echo WORD".to_string())
+        );
+        assert_eq!(
+            reply("part"),
+            Some("Error: Wrong arguments. Usage: part N".to_string())
+        );
+        assert!(
+            reply("nope")
+                .unwrap()
+                .starts_with("Error: Unknown command `nope`")
+        );
+        assert!(
+            reply("help")
+                .unwrap()
+                .contains("echo WORD  Repeats the word")
+        );
+        assert!(matches!(dispatch(&Echo, "  "), Reply::Blank));
+        assert!(matches!(dispatch(&Echo, "quit"), Reply::Quit));
+
+        let Reply::Failed(err) = dispatch(&Echo, "part 1") else {
+            panic!("part 1 of Echo is not implemented");
+        };
+        assert_eq!(err.category(), Category::NotImplemented);
+        let Reply::Failed(err) = dispatch(&Echo, "echo") else {
+            panic!("echo needs a word");
+        };
+        assert_eq!(err.category(), Category::Usage);
+    }
+
+    #[test]
+    fn test_run_commands() {
+        let run = |commands: &[&str]| {
+            let commands = commands.iter().map(|command| command.to_string());
+            let mut printed = Vec::new();
+            let result =
+                run_commands(&Echo, &commands.collect::<Vec<String>>(), |text| {
+                    printed.push(text)
+                });
+            (result, printed)
+        };
+
+        let (result, printed) = run(&["echo a", "", "echo b", "quit"]);
+        assert!(result.is_ok());
+        assert_eq!(printed, ["a", "b"]);
+
+        let (result, printed) = run(&["echo a", "part 2", "echo b"]);
+        let err = result.unwrap_err();
+        assert_eq!(err.category(), Category::NotImplemented);
+        assert_eq!(err.category().exit_code(), 6);
+        assert_eq!(printed, ["a"]);
+
+        let (result, printed) = run(&["echo a", "quit", "echo b"]);
+        let err = result.unwrap_err();
+        assert_eq!(err.category(), Category::Usage);
+        assert_eq!(err.to_string(), "Commands after `quit` are not run: echo b");
+        assert_eq!(printed, ["a"]);
+    }
+}
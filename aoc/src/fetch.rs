@@ -0,0 +1,102 @@
+use crate::client::Client;
+use common::errors::Error;
+use common::io;
+use common::registry::Year;
+use std::path::PathBuf;
+
+/// Where the input came from.
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Downloads the day's input into the inputs directory. An input that is already there
+/// is never downloaded again, only the empty placeholder created by `new-day` is
+/// replaced. `connect` is only called if a download is needed.
+pub fn input(
+    year: &Year, day: u8, connect: impl FnOnce() -> Result<Client, Error>,
+) -> Result<Fetched, Error> {
+    if !year.calendar.contains(&day) {
+        return Err(Error::InvalidDay(day));
+    }
+
+    let path = io::input_path(year, day);
+    if std::fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let input = connect()?.get(&format!("/{}/day/{}/input", year.number, day))?;
+    io::write_file(&path, &input)?;
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serves `body` to a single request and returns the request line and headers.
+    fn serve_once(body: &'static str) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request = BufReader::new(&stream)
+                .lines()
+                .map(Result::unwrap)
+                .take_while(|line| !line.is_empty())
+                .collect();
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+            request
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn test_input() {
+        let dir = std::env::temp_dir().join("aoc_fetch_input");
+        let _ = std::fs::remove_dir_all(&dir);
+        let year = Year {
+            number: 2025,
+            calendar: 1..=12,
+            inputs: dir.to_str().unwrap().to_string().leak(),
+            sources: "",
+        };
+        let path = dir.join("day03.txt");
+
+        let (url, server) = serve_once("1 2 3\n");
+        let connect = || Ok(Client::new(&url, "secret".to_string()));
+        let fetched = input(&year, 3, connect).unwrap();
+        assert_eq!(fetched, Fetched::Downloaded(path.clone()));
+
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2025/day/3/input HTTP/1.1");
+        let cookie = "cookie: session=secret";
+        assert!(
+            request
+                .iter()
+                .any(|header| header.eq_ignore_ascii_case(cookie))
+        );
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1 2 3\n");
+
+        let connect =
+            || -> Result<Client, Error> { panic!("Cached input was downloaded") };
+        assert_eq!(input(&year, 3, connect).unwrap(), Fetched::Cached(path));
+        assert!(matches!(
+            input(&year, 13, connect),
+            Err(Error::InvalidDay(13))
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,262 @@
+use crate::cli::{Cli, Command};
+use crate::client::Client;
+use crate::fetch::Fetched;
+use crate::output::Format;
+use crate::submit::Outcome;
+use clap::Parser;
+use common::answers::{Answers, ExampleAnswers};
+use common::errors::{Category, Error};
+use common::registry::{self, Execution, Year};
+use common::{io, params};
+use std::num::NonZeroUsize;
+use std::thread;
+use std::time::Duration;
+
+pub fn main() {
+    let args = Cli::parse();
+    let year = years::find(args.year).unwrap_or_else(|err| fail(err));
+    params::set(args.param.iter().cloned().collect());
+
+    match &args.command {
+        Some(Command::NewDay { day, title }) => {
+            let files =
+                scaffold::new_day(year, *day, title).unwrap_or_else(|err| fail(err));
+            for file in files {
+                println!("{}", file.display());
+            }
+        },
+        Some(Command::Explore {
+            day,
+            input,
+            example,
+            command,
+        }) => {
+            let solution = year.find(*day, 1).unwrap_or_else(|err| fail(err));
+            let input = match example {
+                Some(example) => io::read_example_file(year, *day, *example),
+                None => io::read_input(year, *day, input.as_deref()),
+            };
+            let input = input.unwrap_or_else(|err| fail(err.into()));
+            explore::explore(solution, input, command).unwrap_or_else(|err| fail(err));
+        },
+        Some(Command::Fetch { day, base_url }) => {
+            let connect = || Client::connect(base_url);
+            match fetch::input(year, *day, connect).unwrap_or_else(|err| fail(err)) {
+                Fetched::Cached(path) => {
+                    println!("Already downloaded: {}", path.display())
+                },
+                Fetched::Downloaded(path) => println!("Downloaded: {}", path.display()),
+            }
+        },
+        Some(Command::Puzzle {
+            day,
+            offline,
+            base_url,
+        }) => {
+            if *offline {
+                let path = puzzle::description_path(year, *day);
+                let description =
+                    io::read_file(&path).unwrap_or_else(|err| fail(err.into()));
+                print!("{}", description);
+            } else {
+                let client = Client::connect(base_url).unwrap_or_else(|err| fail(err));
+                let files =
+                    puzzle::download(year, *day, &client).unwrap_or_else(|err| fail(err));
+                for file in files {
+                    println!("{}", file.display());
+                }
+            }
+        },
+        Some(Command::Submit {
+            day,
+            part,
+            answer,
+            base_url,
+        }) => {
+            let answer = match answer {
+                Some(answer) => answer.clone(),
+                None => submit::solve(year, *day, *part, args.timeout)
+                    .unwrap_or_else(|err| fail(err)),
+            };
+            println!("Submitting {} for day {}. Part {}", answer, day, part);
+
+            let connect = || Client::connect(base_url);
+            let attempt = submit::submit(year, *day, *part, &answer, connect)
+                .unwrap_or_else(|err| fail(err));
+            println!("{}", attempt.outcome.describe());
+            if let Some(retry_after) = attempt.retry_after {
+                let wait = retry_after.saturating_sub(attempt.submitted_at);
+                println!("Next answer is accepted in {}s", wait);
+            }
+
+            let code = match attempt.outcome {
+                Outcome::Correct | Outcome::AlreadySolved => 0,
+                Outcome::Wait => Category::RateLimited.exit_code(),
+                _ => Category::WrongAnswer.exit_code(),
+            };
+            std::process::exit(code);
+        },
+        Some(Command::Bench {
+            day,
+            part,
+            input,
+            iterations,
+            warmup,
+            save_baseline,
+            baseline,
+            threshold,
+        }) => {
+            let solution = year
+                .find(*day, part.unwrap_or(1))
+                .unwrap_or_else(|err| fail(err));
+            let parts = match part {
+                Some(part) if ![1, 2].contains(part) => fail(Error::InvalidPart(*part)),
+                Some(part) => vec![*part],
+                None => (1..=solution.metadata.parts).collect(),
+            };
+            let input = io::read_input(year, *day, input.as_deref())
+                .unwrap_or_else(|err| fail(err.into()));
+            let baseline = baseline.as_deref().map(|path| {
+                let baseline = bench::load_baseline(path).unwrap_or_else(|err| fail(err));
+                if baseline.year != year.number || baseline.day != *day {
+                    fail(Error::InvalidBaseline(
+                        path.to_path_buf(),
+                        format!("recorded for day {} of {}", baseline.day, baseline.year),
+                    ));
+                }
+                baseline
+            });
+
+            if cfg!(debug_assertions) {
+                eprintln!(
+                    "Warning: benchmarking a debug build, use --release for real numbers"
+                );
+            }
+            let measurements = bench::run(solution, &input, &parts, *warmup, *iterations)
+                .unwrap_or_else(|err| fail(err));
+            println!("{}", bench::report(&measurements, input.len()));
+
+            let mut regressed = false;
+            if let Some(baseline) = &baseline {
+                let comparisons = bench::compare(&measurements, baseline);
+                println!();
+                println!("{}", bench::comparison_report(&comparisons, *threshold));
+                regressed = comparisons
+                    .iter()
+                    .any(|comparison| comparison.is_regression(*threshold));
+            }
+
+            if let Some(path) = save_baseline {
+                let baseline = bench::Baseline {
+                    year: year.number,
+                    day: *day,
+                    iterations: *iterations,
+                    measurements,
+                };
+                bench::save_baseline(path, &baseline).unwrap_or_else(|err| fail(err));
+            }
+
+            if regressed {
+                std::process::exit(Category::Regression.exit_code());
+            }
+        },
+        None => run(year, &args),
+    }
+}
+
+/// Runs parts: all of them, a range of days, or a single part.
+fn run(year: &Year, args: &Cli) {
+    if args.all || args.days.is_some() || args.verify {
+        let answers = match args.verify {
+            true => Some(Answers::load(year).unwrap_or_else(|err| fail(err))),
+            false => None,
+        };
+
+        let range = args.days.clone().unwrap_or(year.calendar.clone());
+        let jobs = args.jobs.unwrap_or_else(|| {
+            thread::available_parallelism().unwrap_or(NonZeroUsize::MIN)
+        });
+        let executions = runner::run_all(year, range, jobs, args.timeout);
+        let records = runner::records(&executions, answers.as_ref());
+        println!(
+            "{}",
+            output::render(args.format, year.number, &executions, &records)
+        );
+
+        std::process::exit(runner::exit_code(&records));
+    }
+
+    let (Some(day), Some(part)) = (args.day, args.part) else {
+        println!("{}", year.list());
+        return;
+    };
+
+    if ![1, 2].contains(&part) {
+        fail(Error::InvalidPart(part));
+    }
+
+    let solution = year.find(day, part).unwrap_or_else(|err| fail(err));
+
+    if args.watch {
+        watch::watch(year, day, part, args.timeout);
+    }
+
+    let answers = match args.example {
+        Some(example) => {
+            let answers = ExampleAnswers::load(year).unwrap_or_else(|err| fail(err));
+            Some(answers.example(example))
+        },
+        None => None,
+    };
+
+    let input = match args.example {
+        Some(example) => io::read_example_file(year, day, example),
+        None => io::read_input(year, day, args.input.as_deref()),
+    };
+
+    if let Format::Text = args.format {
+        let input = input.unwrap_or_else(|err| fail(err.into()));
+
+        let expected = answers.as_ref().and_then(|answers| answers.get(day, part));
+        match registry::run(input, solution, part, expected, args.timeout) {
+            Ok(output) => println!("{}", output),
+            Err(e) => fail(e),
+        }
+        return;
+    }
+
+    let execution = match input {
+        Ok(input) => solution.execute(input, &[part], args.timeout),
+        Err(err) => Execution::failed(day, Duration::ZERO, err.into()),
+    };
+    let executions = vec![execution];
+    let records = runner::records(&executions, answers.as_ref());
+    println!(
+        "{}",
+        output::render(args.format, year.number, &executions, &records)
+    );
+
+    std::process::exit(runner::exit_code(&records));
+}
+
+/// Prints the error and exits with the code of its category.
+fn fail(err: Error) -> ! {
+    eprintln!("Error: {}", err);
+    if let Some(span) = err.span() {
+        eprintln!("{}", span.render());
+    }
+    std::process::exit(err.category().exit_code());
+}
+
+mod bench;
+mod cli;
+mod client;
+mod explore;
+mod fetch;
+mod output;
+mod puzzle;
+mod runner;
+mod scaffold;
+mod submit;
+mod watch;
+mod years;
@@ -0,0 +1,125 @@
+use crate::runner::{self, Record, Status};
+use clap::ValueEnum;
+use common::answer::Answer;
+use common::registry::Execution;
+use serde::Serialize;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+/// Flat form of a [`Record`] for machine-readable formats.
+#[derive(Serialize)]
+struct Entry<'a> {
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    answer: Option<&'a Answer>,
+    expected: Option<&'a str>,
+    parse_ns: u64,
+    solve_ns: u64,
+    status: Status,
+    error_kind: Option<&'static str>,
+    error: Option<String>,
+}
+
+impl<'a> Entry<'a> {
+    fn new(year: u16, record: &Record<'a>) -> Self {
+        Self {
+            year,
+            day: record.day,
+            part: record.part,
+            answer: record.answer.ok(),
+            expected: record.expected,
+            parse_ns: nanoseconds(record.parse_time),
+            solve_ns: nanoseconds(record.solve_time),
+            status: record.status,
+            error_kind: record.answer.err().map(|err| err.kind()),
+            error: record.answer.err().map(|err| err.to_string()),
+        }
+    }
+}
+
+pub fn render(
+    format: Format, year: u16, executions: &[Execution], records: &[Record],
+) -> String {
+    match format {
+        Format::Text => runner::summary(executions, records),
+        Format::Json => json(year, records),
+        Format::Csv => csv(year, records),
+    }
+}
+
+fn json(year: u16, records: &[Record]) -> String {
+    let entries = records
+        .iter()
+        .map(|record| Entry::new(year, record))
+        .collect::<Vec<Entry>>();
+
+    serde_json::to_string_pretty(&entries).unwrap_or_else(|err| {
+        // Entries contain only strings and numbers, so this is not expected to happen.
+        format!("{{\"error\": \"{}\"}}", err)
+    })
+}
+
+fn csv(year: u16, records: &[Record]) -> String {
+    let mut lines = vec![
+        "year,day,part,answer,expected,parse_ns,solve_ns,status,error_kind,error"
+            .to_string(),
+    ];
+
+    for record in records {
+        let entry = Entry::new(year, record);
+        let status = entry.status.to_string();
+        let fields = [
+            entry.year.to_string(),
+            entry.day.to_string(),
+            entry.part.map(|part| part.to_string()).unwrap_or_default(),
+            entry.answer.map(Answer::to_string).unwrap_or_default(),
+            entry.expected.unwrap_or_default().to_string(),
+            entry.parse_ns.to_string(),
+            entry.solve_ns.to_string(),
+            status,
+            entry.error_kind.unwrap_or_default().to_string(),
+            entry.error.unwrap_or_default(),
+        ];
+
+        let line = fields
+            .iter()
+            .map(|field| csv_field(field))
+            .collect::<Vec<String>>()
+            .join(",");
+        lines.push(line);
+    }
+
+    lines.join("\n")
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn nanoseconds(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("123"), "123");
+        assert_eq!(csv_field("a, b"), "\"a, b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
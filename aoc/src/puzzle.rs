@@ -0,0 +1,262 @@
+use crate::client::Client;
+use common::errors::Error;
+use common::io;
+use common::registry::Year;
+use std::path::{Path, PathBuf};
+
+/// Piece of the puzzle page.
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    Open {
+        name: &'a str,
+        href: Option<&'a str>,
+    },
+    Close(&'a str),
+    Text(&'a str),
+}
+
+/// Description of the puzzle, stored as `puzzle.md` in the day's module directory.
+pub fn description_path(year: &Year, day: u8) -> PathBuf {
+    Path::new(year.sources)
+        .join("days")
+        .join(format!("day{:02}", day))
+        .join("puzzle.md")
+}
+
+/// Downloads the puzzle page, stores its description as markdown and its example blocks
+/// as example inputs. Existing examples are kept, only new blocks get the next free
+/// numbers, so running it again after part 2 unlocks adds part 2's examples. Returns
+/// the written files.
+pub fn download(year: &Year, day: u8, client: &Client) -> Result<Vec<PathBuf>, Error> {
+    if !year.calendar.contains(&day) {
+        return Err(Error::InvalidDay(day));
+    }
+
+    let page = client.get(&format!("/{}/day/{}", year.number, day))?;
+    let articles = articles(&page);
+    if articles.is_empty() {
+        let url = format!("/{}/day/{}", year.number, day);
+        return Err(Error::Http(
+            url,
+            "No puzzle description on the page".to_string(),
+        ));
+    }
+
+    let path = description_path(year, day);
+    let markdown = articles
+        .iter()
+        .map(|article| to_markdown(article))
+        .collect::<Vec<String>>()
+        .join("\n");
+    io::write_file(&path, &markdown)?;
+    let mut written = vec![path];
+
+    let mut examples = (1..=u8::MAX)
+        .map_while(|example| io::read_example_file(year, day, example).ok())
+        .collect::<Vec<String>>();
+    for block in articles.iter().flat_map(|article| code_blocks(article)) {
+        if examples.contains(&block) {
+            continue;
+        }
+        let Ok(number) = u8::try_from(examples.len() + 1) else {
+            break;
+        };
+        let path = io::example_path(year, day, number);
+        io::write_new_file(&path, &block)?;
+        written.push(path);
+        examples.push(block);
+    }
+
+    Ok(written)
+}
+
+/// Contents of the page's `<article>` elements, one per unlocked part.
+fn articles(page: &str) -> Vec<&str> {
+    let mut articles = Vec::new();
+    let mut rest = page;
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>') else {
+            break;
+        };
+        let content = &rest[start + open_end + 1..];
+        let Some(end) = content.find("</article>") else {
+            break;
+        };
+        articles.push(&content[..end]);
+        rest = &content[end..];
+    }
+    articles
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+        let Some(end) = rest[start..].find('>') else {
+            tokens.push(Token::Text(&rest[start..]));
+            break;
+        };
+
+        let tag = &rest[start + 1..start + end];
+        match tag.strip_prefix('/') {
+            Some(name) => tokens.push(Token::Close(name.trim())),
+            None => {
+                let name = tag
+                    .split(|c: char| c.is_whitespace() || c == '/')
+                    .next()
+                    .unwrap_or_default();
+                let href = tag
+                    .split_once("href=\"")
+                    .and_then(|(_, value)| value.split('"').next());
+                tokens.push(Token::Open { name, href });
+            },
+        }
+        rest = &rest[start + end + 1..];
+    }
+    tokens
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Markdown for reading in a terminal: headers, paragraphs, lists, emphasis, inline
+/// code, links and fenced code blocks.
+fn to_markdown(article: &str) -> String {
+    let mut markdown = String::new();
+    let mut in_pre = false;
+    let mut links = Vec::new();
+
+    for token in tokenize(article) {
+        match token {
+            Token::Open { name: "h2", .. } => markdown.push_str("## "),
+            Token::Open { name: "pre", .. } => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            },
+            Token::Close("pre") => {
+                in_pre = false;
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("```\n\n");
+            },
+            Token::Open { name: "code", .. } | Token::Close("code") if !in_pre => {
+                markdown.push('`');
+            },
+            Token::Open { name: "em", .. } | Token::Close("em") if !in_pre => {
+                markdown.push('*');
+            },
+            Token::Open { name: "li", .. } => markdown.push_str("- "),
+            Token::Open { name: "a", href } => {
+                links.push(href.unwrap_or_default());
+                markdown.push('[');
+            },
+            Token::Close("a") => {
+                let href = links.pop().unwrap_or_default();
+                markdown.push_str(&format!("]({})", href));
+            },
+            Token::Open { name: "br", .. } | Token::Close("li") | Token::Close("ul") => {
+                markdown.push('\n');
+            },
+            Token::Close("h2") | Token::Close("p") => markdown.push_str("\n\n"),
+            Token::Text(text) if in_pre => markdown.push_str(&decode_entities(text)),
+            Token::Text(text) => {
+                let text = decode_entities(text);
+                let collapsed = text.split_whitespace().collect::<Vec<&str>>().join(" ");
+                let space = |edge: Option<char>| edge.is_some_and(char::is_whitespace);
+                if space(text.chars().next()) && !markdown.ends_with(char::is_whitespace)
+                {
+                    markdown.push(' ');
+                }
+                markdown.push_str(&collapsed);
+                if space(text.chars().last()) && !collapsed.is_empty() {
+                    markdown.push(' ');
+                }
+            },
+            _ => {},
+        }
+    }
+
+    markdown.trim_end().to_string() + "\n"
+}
+
+/// Texts of the `<pre><code>` blocks, with the highlighting markup removed.
+fn code_blocks(article: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut block = None;
+    for token in tokenize(article) {
+        match token {
+            Token::Open { name: "pre", .. } => block = Some(String::new()),
+            Token::Close("pre") => {
+                if let Some(mut text) = block.take() {
+                    if !text.ends_with('\n') {
+                        text.push('\n');
+                    }
+                    blocks.push(text);
+                }
+            },
+            Token::Text(text) => {
+                if let Some(block) = &mut block {
+                    block.push_str(&decode_entities(text));
+                }
+            },
+            _ => {},
+        }
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Test ---</h2><p>Read <a href="/about">this</a>
+and find the <em>sum</em> of <code>a &lt; b</code>.</p>
+<p>For example:</p>
+<pre><code>1 2
+<em>3</em> 4
+</code></pre>
+<ul><li>One</li><li>Two</li></ul>
+</article>
+<p>Your puzzle answer was <code>42</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><pre><code>1 2
+3 4
+</code></pre><pre><code>5</code></pre></article>
+</main>"#;
+
+    #[test]
+    fn test_to_markdown() {
+        let articles = articles(PAGE);
+        assert_eq!(articles.len(), 2);
+
+        assert_eq!(
+            to_markdown(articles[0]),
+            "## --- Day 1: Test ---\n\nRead [this](/about) and find the *sum* of `a < b`.\
+             \n\nFor example:\n\n```\n1 2\n3 4\n```\n\n- One\n- Two\n"
+        );
+    }
+
+    #[test]
+    fn test_code_blocks() {
+        let blocks = articles(PAGE)
+            .into_iter()
+            .flat_map(code_blocks)
+            .collect::<Vec<String>>();
+
+        assert_eq!(blocks, vec!["1 2\n3 4\n", "1 2\n3 4\n", "5\n"]);
+    }
+}
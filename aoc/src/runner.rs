@@ -0,0 +1,335 @@
+use common::answer::Answer;
+use common::answers::Answers;
+use common::errors::{Category, Error};
+use common::io;
+use common::registry::{Day, Execution, Year};
+use serde::Serialize;
+use std::fmt;
+use std::num::NonZeroUsize;
+use std::ops::RangeInclusive;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+/// Runs every registered part of the days in range against their input files.
+///
+/// Days are independent jobs spread over `jobs` threads. Each parses its input once and
+/// solves its parts one after another, each part limited to `timeout`. The executions
+/// keep the order of the days. With more than one job, the timings include contention
+/// with the days running alongside.
+pub fn run_all(
+    year: &Year, range: RangeInclusive<u8>, jobs: NonZeroUsize, timeout: Option<Duration>,
+) -> Vec<Execution> {
+    let days = year
+        .days()
+        .into_iter()
+        .filter(|day| range.contains(&day.metadata.day))
+        .collect::<Vec<&Day>>();
+
+    run_parallel(&days, jobs, |day| {
+        let parts = (1..=day.metadata.parts).collect::<Vec<u8>>();
+        match io::read_input_file(year, day.metadata.day) {
+            Ok(input) => day.execute(input, &parts, timeout),
+            Err(err) => Execution::failed(day.metadata.day, Duration::ZERO, err.into()),
+        }
+    })
+}
+
+/// Applies `job` to every task on up to `jobs` threads. Results are in task order.
+fn run_parallel<T: Sync, R: Send>(
+    tasks: &[T], jobs: NonZeroUsize, job: impl Fn(&T) -> R + Sync,
+) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.get().min(tasks.len()) {
+            let (sender, next, job) = (sender.clone(), &next, &job);
+            scope.spawn(move || {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(task) = tasks.get(index) else {
+                        break;
+                    };
+                    // The receiver outlives the workers, so sending can't fail.
+                    let _ = sender.send((index, job(task)));
+                }
+            });
+        }
+    });
+    drop(sender);
+
+    let mut results = receiver.into_iter().collect::<Vec<(usize, R)>>();
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Outcome of a single part, as shown in the summary table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Status {
+    /// Solved, but not verified against a known answer.
+    #[serde(rename = "OK")]
+    Solved,
+    Pass,
+    Fail,
+    /// Solved, but no known answer is recorded.
+    Unknown,
+    Error,
+    /// Aborted or finished after the `--timeout` deadline.
+    Timeout,
+}
+
+impl Status {
+    pub fn is_failed(&self) -> bool {
+        matches!(self, Status::Fail | Status::Error | Status::Timeout)
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Status::Solved => "OK",
+            Status::Pass => "PASS",
+            Status::Fail => "FAIL",
+            Status::Unknown => "UNKNOWN",
+            Status::Error => "ERROR",
+            Status::Timeout => "TIMEOUT",
+        };
+        write!(f, "{}", status)
+    }
+}
+
+/// One row of the summary: a solved part, or a day whose input failed to load or parse.
+pub struct Record<'a> {
+    pub day: u8,
+    pub part: Option<u8>,
+    pub answer: Result<&'a Answer, &'a Error>,
+    pub expected: Option<&'a str>,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+    pub status: Status,
+}
+
+impl Record<'_> {
+    /// Category of the failure, `None` if the record did not fail.
+    pub fn failure(&self) -> Option<Category> {
+        match (&self.status, &self.answer) {
+            (Status::Fail, _) => Some(Category::WrongAnswer),
+            (Status::Error | Status::Timeout, Err(err)) => Some(err.category()),
+            _ => None,
+        }
+    }
+}
+
+/// Exit code of the first failed record, 0 if nothing failed.
+pub fn exit_code(records: &[Record]) -> i32 {
+    records
+        .iter()
+        .find_map(Record::failure)
+        .map_or(0, Category::exit_code)
+}
+
+/// Flattens executions into records. Answers are verified if known answers are given.
+pub fn records<'a>(
+    executions: &'a [Execution], answers: Option<&'a Answers>,
+) -> Vec<Record<'a>> {
+    let mut records = Vec::new();
+
+    for execution in executions {
+        let runs = match &execution.outcome {
+            Ok(runs) => runs,
+            Err(err) => {
+                records.push(Record {
+                    day: execution.day,
+                    part: None,
+                    answer: Err(err),
+                    expected: None,
+                    parse_time: execution.parse_time,
+                    solve_time: Duration::ZERO,
+                    status: Status::Error,
+                });
+                continue;
+            },
+        };
+
+        for run in runs {
+            let expected =
+                answers.and_then(|answers| answers.get(execution.day, run.part));
+            let status = match (&run.answer, answers, expected) {
+                (Err(err), _, _) if err.category() == Category::Timeout => {
+                    Status::Timeout
+                },
+                (Err(_), _, _) => Status::Error,
+                (Ok(_), None, _) => Status::Solved,
+                (Ok(_), Some(_), None) => Status::Unknown,
+                (Ok(answer), Some(_), Some(expected)) if answer.matches(expected) => {
+                    Status::Pass
+                },
+                (Ok(_), Some(_), Some(_)) => Status::Fail,
+            };
+
+            records.push(Record {
+                day: execution.day,
+                part: Some(run.part),
+                answer: run.answer.as_ref(),
+                expected,
+                parse_time: execution.parse_time,
+                solve_time: run.solve_time,
+                status,
+            });
+        }
+    }
+
+    records
+}
+
+pub fn summary(executions: &[Execution], records: &[Record]) -> String {
+    const HEADER: [&str; 6] = ["Day", "Part", "Answer", "Parse", "Solve", "Status"];
+
+    let mut rows: Vec<[String; 6]> = Vec::new();
+    for record in records {
+        let answer = match (&record.answer, record.status) {
+            (Ok(answer), Status::Fail) => {
+                let expected = record.expected.unwrap_or("-");
+                format!("{} (expected {})", answer.single_line(), expected)
+            },
+            (Ok(answer), _) => answer.single_line(),
+            (Err(err), _) => format!("Error: {}", err),
+        };
+        let solve_time = match record.part {
+            Some(_) => format_duration(record.solve_time),
+            None => "-".to_string(),
+        };
+
+        rows.push([
+            format!("{:02}", record.day),
+            record
+                .part
+                .map(|part| part.to_string())
+                .unwrap_or("-".to_string()),
+            answer,
+            format_duration(record.parse_time),
+            solve_time,
+            record.status.to_string(),
+        ]);
+    }
+
+    let mut lines = table(HEADER, &rows, &[2, 5]);
+
+    let total_time = executions.iter().map(Execution::total_time).sum();
+    let failed = records
+        .iter()
+        .filter(|record| record.status.is_failed())
+        .count();
+    lines.push(String::new());
+    lines.push(format!(
+        "Total: {}. Days: {}. Failed: {}",
+        format_duration(total_time),
+        executions.len(),
+        failed
+    ));
+
+    lines.join("\n")
+}
+
+/// Renders the rows under a header with a separator line. Columns listed in `left` are
+/// aligned to the left, the rest to the right.
+pub fn table<const N: usize>(
+    header: [&str; N], rows: &[[String; N]], left: &[usize],
+) -> Vec<String> {
+    let mut widths = header.map(|title| title.chars().count());
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |row: [&str; N]| -> String {
+        row.iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(index, (cell, width))| match left.contains(&index) {
+                true => format!("{:<width$}", cell),
+                false => format!("{:>width$}", cell),
+            })
+            .collect::<Vec<String>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut lines = vec![format_row(header)];
+    lines.push(
+        widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<String>>()
+            .join("-+-"),
+    );
+    for row in rows {
+        lines.push(format_row(row.each_ref().map(String::as_str)));
+    }
+
+    lines
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+
+    if nanos < 1_000 {
+        format!("{} ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2} µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2} ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2} s", duration.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::registry::PartRun;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999 ns");
+        assert_eq!(format_duration(Duration::from_nanos(1_500)), "1.50 µs");
+        assert_eq!(format_duration(Duration::from_micros(2_250)), "2.25 ms");
+        assert_eq!(format_duration(Duration::from_millis(3_000)), "3.00 s");
+    }
+
+    #[test]
+    fn test_run_parallel() {
+        let tasks = (0..100).collect::<Vec<u64>>();
+        let jobs = NonZeroUsize::new(4).unwrap();
+
+        let results = run_parallel(&tasks, jobs, |task| task * 2);
+
+        assert_eq!(results, (0..100).map(|task| task * 2).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn test_exit_code() {
+        let solved = Execution {
+            day: 1,
+            parse_time: Duration::ZERO,
+            outcome: Ok(vec![PartRun {
+                part: 1,
+                answer: Ok(Answer::from(42u64)),
+                solve_time: Duration::ZERO,
+            }]),
+        };
+        assert_eq!(exit_code(&records(std::slice::from_ref(&solved), None)), 0);
+
+        let failed = Execution::failed(2, Duration::ZERO, Error::NotImplemented(2, 1));
+        let executions = [solved, failed];
+        assert_eq!(exit_code(&records(&executions, None)), 6);
+
+        let answers: Answers = "1 1 41".parse().unwrap();
+        assert_eq!(exit_code(&records(&executions, Some(&answers))), 1);
+    }
+}
@@ -0,0 +1,109 @@
+use common::errors::Error;
+use common::io;
+use common::registry::Year;
+use std::path::{Path, PathBuf};
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs.template");
+const ERRORS_TEMPLATE: &str = include_str!("../templates/errors.rs.template");
+const MODEL_TEMPLATE: &str = include_str!("../templates/model.rs.template");
+const PART1_TEMPLATE: &str = include_str!("../templates/part1.rs.template");
+
+/// Generates the module of a new day, registers it in `days.rs` and creates an empty
+/// input file. Returns the created or changed files.
+pub fn new_day(year: &Year, day: u8, title: &str) -> Result<Vec<PathBuf>, Error> {
+    if !year.calendar.contains(&day) {
+        return Err(Error::InvalidDay(day));
+    }
+
+    let days_dir = Path::new(year.sources).join("days");
+    let module = format!("day{:02}", day);
+    let module_file = days_dir.join(format!("{}.rs", module));
+    let module_dir = days_dir.join(&module);
+
+    let files = [
+        (module_file, DAY_TEMPLATE),
+        (module_dir.join("errors.rs"), ERRORS_TEMPLATE),
+        (module_dir.join("model.rs"), MODEL_TEMPLATE),
+        (module_dir.join("part1.rs"), PART1_TEMPLATE),
+    ];
+
+    // Check everything first, so nothing is generated halfway. The directory itself may
+    // exist already, e.g. with a downloaded puzzle description.
+    for (path, _) in &files {
+        if path.exists() {
+            return Err(io::IoError::AlreadyExists(path.clone()).into());
+        }
+    }
+    let registry_file = Path::new(year.sources).join("days.rs");
+    let registry = register(&io::read_file(&registry_file)?, &module);
+
+    let mut changed = Vec::new();
+    for (path, template) in files {
+        io::write_new_file(&path, &render(template, year, day, title))?;
+        changed.push(path);
+    }
+
+    io::write_file(&registry_file, &registry)?;
+    changed.push(registry_file);
+
+    let input = io::input_path(year, day);
+    if !input.exists() {
+        io::write_file(&input, "")?;
+        changed.push(input);
+    }
+
+    Ok(changed)
+}
+
+fn render(template: &str, year: &Year, day: u8, title: &str) -> String {
+    template
+        .replace("{DD}", &format!("{:02}", day))
+        .replace("{DAY}", &day.to_string())
+        .replace("{YEAR}", &year.number.to_string())
+        .replace("{TITLE}", &format!("{:?}", title))
+}
+
+/// Adds `pub mod <module>;` to the registry, keeping the declarations sorted.
+fn register(registry: &str, module: &str) -> String {
+    let declaration = format!("pub mod {};", module);
+    let mut lines = registry.lines().collect::<Vec<&str>>();
+
+    let declarations = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with("pub mod day"))
+        .map(|(index, line)| (index, *line))
+        .collect::<Vec<(usize, &str)>>();
+    let index = match declarations
+        .iter()
+        .find(|(_, line)| *line > declaration.as_str())
+    {
+        Some((index, _)) => *index,
+        None => declarations.last().map_or(0, |(index, _)| index + 1),
+    };
+    lines.insert(index, &declaration);
+
+    let mut registry = lines.join("\n");
+    registry.push('\n');
+    registry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register() {
+        let registry = "pub mod day01;\npub mod day03;\n\n#[cfg(test)]\nmod tests {}\n";
+
+        assert_eq!(
+            register(registry, "day02"),
+            "pub mod day01;\npub mod day02;\npub mod day03;\n\n#[cfg(test)]\nmod tests {}\n"
+        );
+        assert_eq!(
+            register(registry, "day04"),
+            "pub mod day01;\npub mod day03;\npub mod day04;\n\n#[cfg(test)]\nmod tests {}\n"
+        );
+        assert_eq!(register("", "day01"), "pub mod day01;\n");
+    }
+}
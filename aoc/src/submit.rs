@@ -0,0 +1,350 @@
+use crate::client::Client;
+use common::answer::{Answer, BigInt};
+use common::answers::Answers;
+use common::errors::Error;
+use common::io;
+use common::registry::Year;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// What the website said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after the previous answer, nothing was checked.
+    Wait,
+    /// The part is solved already, nothing was checked.
+    AlreadySolved,
+}
+
+/// A submitted answer, as recorded in the history.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Unix time in seconds.
+    pub submitted_at: u64,
+    /// Unix time from which the website accepts answers again, if it asked to wait.
+    pub retry_after: Option<u64>,
+}
+
+/// Every attempt of the year, stored in `submissions.json` next to the inputs.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct History {
+    attempts: Vec<Attempt>,
+}
+
+impl Outcome {
+    /// Recognizes the outcome in the text of the response page.
+    fn parse(text: &str) -> Option<Self> {
+        if text.contains("That's the right answer") {
+            Some(Outcome::Correct)
+        } else if text.contains("That's not the right answer") {
+            match text {
+                text if text.contains("answer is too high") => Some(Outcome::TooHigh),
+                text if text.contains("answer is too low") => Some(Outcome::TooLow),
+                _ => Some(Outcome::Incorrect),
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Some(Outcome::Wait)
+        } else if text.contains("You don't seem to be solving the right level") {
+            Some(Outcome::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_wrong(self) -> bool {
+        matches!(
+            self,
+            Outcome::Incorrect | Outcome::TooHigh | Outcome::TooLow
+        )
+    }
+
+    pub fn describe(self) -> &'static str {
+        match self {
+            Outcome::Correct => "Correct",
+            Outcome::Incorrect => "Incorrect",
+            Outcome::TooHigh => "Too high",
+            Outcome::TooLow => "Too low",
+            Outcome::Wait => "Submitted too recently",
+            Outcome::AlreadySolved => "Already solved",
+        }
+    }
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        match io::read_file(path) {
+            Ok(content) => serde_json::from_str(&content).map_err(|err| {
+                Error::InvalidHistory(path.to_path_buf(), err.to_string())
+            }),
+            Err(io::IoError::NotFound(_)) => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|err| Error::InvalidHistory(path.to_path_buf(), err.to_string()))?;
+        Ok(io::write_file(path, &content)?)
+    }
+
+    /// Why the answer mustn't be sent: the website still asks to wait, the part is
+    /// solved, or the answer is known to be wrong, also by a too high or too low bound.
+    fn check(&self, day: u8, part: u8, answer: &str, now: u64) -> Result<(), String> {
+        if let Some(retry_after) = self.attempts.last().and_then(|last| last.retry_after)
+            && retry_after > now
+        {
+            return Err(format!(
+                "wait {}s before submitting again",
+                retry_after - now
+            ));
+        }
+
+        let attempts = self
+            .attempts
+            .iter()
+            .filter(|attempt| attempt.day == day && attempt.part == part);
+        let value = answer.parse::<BigInt>().ok();
+        for attempt in attempts {
+            let known = attempt.answer.parse::<BigInt>().ok();
+            let refusal = match (attempt.outcome, &value, &known) {
+                (Outcome::Correct, _, _) => "the part is solved already",
+                (outcome, _, _) if outcome.is_wrong() && attempt.answer == answer => {
+                    "the answer was rejected before"
+                },
+                (Outcome::TooHigh, Some(value), Some(known)) if value >= known => {
+                    "a lower answer was too high"
+                },
+                (Outcome::TooLow, Some(value), Some(known)) if value <= known => {
+                    "a higher answer was too low"
+                },
+                _ => continue,
+            };
+            let outcome = attempt.outcome.describe().to_lowercase();
+            return Err(format!("{}: {} ({})", refusal, attempt.answer, outcome));
+        }
+
+        Ok(())
+    }
+}
+
+/// Answer of the part on the day's input, for submitting it without `--answer`.
+pub fn solve(
+    year: &Year, day: u8, part: u8, timeout: Option<Duration>,
+) -> Result<String, Error> {
+    let solution = year.find(day, part)?;
+    let input = io::read_input_file(year, day)?;
+    match solution.run(input, part, timeout)? {
+        Answer::Grid(_) => Err(Error::SubmissionRefused(
+            "read the letters of the grid and pass them with --answer".to_string(),
+        )),
+        answer => Ok(answer.to_string()),
+    }
+}
+
+fn history_path(year: &Year) -> PathBuf {
+    io::input_dir(year).join("submissions.json")
+}
+
+/// Submits the answer of the part, unless the history shows it's pointless. Every
+/// answer sent is recorded in the history, correct ones in `answers.txt` as well.
+pub fn submit(
+    year: &Year, day: u8, part: u8, answer: &str,
+    connect: impl FnOnce() -> Result<Client, Error>,
+) -> Result<Attempt, Error> {
+    if !year.calendar.contains(&day) {
+        return Err(Error::InvalidDay(day));
+    }
+    if ![1, 2].contains(&part) {
+        return Err(Error::InvalidPart(part));
+    }
+    let answer = validate(answer).map_err(Error::SubmissionRefused)?;
+
+    let path = history_path(year);
+    let mut history = History::load(&path)?;
+    let now = unix_time(SystemTime::now());
+    history
+        .check(day, part, answer, now)
+        .map_err(Error::SubmissionRefused)?;
+
+    let url = format!("/{}/day/{}/answer", year.number, day);
+    let response =
+        connect()?.post(&url, &[("level", &part.to_string()), ("answer", answer)])?;
+    let text = page_text(&response);
+    let outcome = Outcome::parse(&text)
+        .ok_or_else(|| Error::Http(url, format!("Unrecognized response: {}", text)))?;
+
+    let attempt = Attempt {
+        day,
+        part,
+        answer: answer.to_string(),
+        outcome,
+        submitted_at: now,
+        retry_after: parse_wait(&text).map(|wait| now + wait.as_secs()),
+    };
+    history.attempts.push(attempt.clone());
+    history.save(&path)?;
+
+    if outcome == Outcome::Correct {
+        Answers::record(year, day, part, answer)?;
+    }
+    Ok(attempt)
+}
+
+/// The answer without surrounding whitespace. It has to be a single word, like the
+/// answers of `answers.txt`.
+fn validate(answer: &str) -> Result<&str, String> {
+    let answer = answer.trim();
+    if answer.is_empty() {
+        return Err("the answer is empty".to_string());
+    }
+    if answer.contains(char::is_whitespace) {
+        return Err(format!("the answer contains whitespace: {:?}", answer));
+    }
+    Ok(answer)
+}
+
+fn unix_time(time: SystemTime) -> u64 {
+    time.duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Text of the page's `<article>`, or of the whole page without one, with the tags
+/// stripped and whitespace collapsed.
+fn page_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {},
+        }
+    }
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// How long the website asks to wait: `You have 1m 5s left to wait` after answering too
+/// soon, `Please wait one minute before trying again` after a wrong answer.
+fn parse_wait(text: &str) -> Option<Duration> {
+    let lower = text.to_lowercase();
+    let phrase = match lower.split_once("you have ") {
+        Some((_, rest)) if rest.contains(" left to wait") => {
+            rest.split(" left to wait").next()
+        },
+        _ => lower
+            .split_once("please wait ")
+            .and_then(|(_, rest)| rest.split(" before trying again").next()),
+    }?;
+
+    let words = phrase.split_whitespace().collect::<Vec<&str>>();
+    let mut seconds = 0;
+    let mut index = 0;
+    while index < words.len() {
+        // Either `5m`, `30s` or `5 minutes`, `one minute`.
+        let word = words[index];
+        let (number, unit) = match word.find(|c: char| !c.is_ascii_digit()) {
+            Some(split) if split > 0 => (&word[..split], &word[split..]),
+            _ => {
+                index += 1;
+                (word, *words.get(index)?)
+            },
+        };
+        let number = match number {
+            "one" | "a" => 1,
+            number => number.parse::<u64>().ok()?,
+        };
+        seconds += match unit.chars().next()? {
+            'h' => number * 3600,
+            'm' => number * 60,
+            's' => number,
+            _ => return None,
+        };
+        index += 1;
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outcome() {
+        let page = "<main><article><p>That's not the right answer; your answer is too \
+                    high. Please wait one minute before trying again.</p></article></main>";
+        let text = page_text(page);
+
+        assert_eq!(Outcome::parse(&text), Some(Outcome::TooHigh));
+        assert_eq!(parse_wait(&text), Some(Duration::from_secs(60)));
+
+        let text = "You gave an answer too recently. You have 1m 5s left to wait.";
+        assert_eq!(Outcome::parse(text), Some(Outcome::Wait));
+        assert_eq!(parse_wait(text), Some(Duration::from_secs(65)));
+
+        let text = "That's the right answer! You are one gold star closer.";
+        assert_eq!(Outcome::parse(text), Some(Outcome::Correct));
+        assert_eq!(parse_wait(text), None);
+        assert_eq!(Outcome::parse("Something else"), None);
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(validate(" 42\n"), Ok("42"));
+        assert_eq!(validate("abc"), Ok("abc"));
+        assert!(validate(" \n").is_err());
+        assert!(validate("4 2").is_err());
+        assert!(validate("ab\ncd").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let attempt = |part, answer: &str, outcome, retry_after| Attempt {
+            day: 1,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            submitted_at: 0,
+            retry_after,
+        };
+        let mut history = History {
+            attempts: vec![
+                attempt(1, "7", Outcome::Correct, None),
+                attempt(2, "abc", Outcome::Incorrect, None),
+                attempt(2, "100", Outcome::TooHigh, None),
+                attempt(2, "10", Outcome::TooLow, Some(60)),
+            ],
+        };
+
+        assert!(history.check(1, 1, "8", 60).is_err());
+        assert!(history.check(1, 2, "abc", 60).is_err());
+        assert!(history.check(1, 2, "150", 60).is_err());
+        assert!(history.check(1, 2, "10", 60).is_err());
+        assert!(history.check(1, 2, "50", 60).is_ok());
+        assert!(history.check(2, 1, "50", 60).is_ok());
+
+        assert!(history.check(1, 2, "50", 59).is_err());
+        history
+            .attempts
+            .push(attempt(2, "20", Outcome::TooLow, None));
+        assert!(history.check(1, 2, "15", 0).is_err());
+    }
+}
@@ -0,0 +1,298 @@
+use crate::runner::{format_duration, table};
+use common::answer::Answer;
+use common::registry::Year;
+use common::{io, params};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Modification times of the watched files, `None` for missing ones.
+type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+/// What a part is run against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Target {
+    Input,
+    Example(u8),
+}
+
+/// Answer of one run, as reported by `--format json`.
+#[derive(Debug, Deserialize)]
+struct Report {
+    answer: Option<Answer>,
+    solve_ns: u64,
+    status: String,
+    error: Option<String>,
+}
+
+/// Message of `cargo build --message-format json`. Only artifacts name an executable.
+#[derive(Debug, Deserialize)]
+struct Message {
+    reason: String,
+    executable: Option<PathBuf>,
+}
+
+/// Watches the day's module, input and examples, and rebuilds and re-runs the part on
+/// the input and every example after each change. Runs until interrupted.
+pub fn watch(year: &Year, day: u8, part: u8, timeout: Option<Duration>) -> ! {
+    let mut snapshot = Snapshot::new();
+    let mut previous = BTreeMap::<Target, Answer>::new();
+
+    loop {
+        let current = take_snapshot(&watched_files(year, day));
+        let changed = changes(&snapshot, &current);
+        let first = snapshot.is_empty();
+        snapshot = current;
+        if changed.is_empty() {
+            thread::sleep(POLL_INTERVAL);
+            continue;
+        }
+
+        println!("--- Day {}. Part {} ---", day, part);
+        match first {
+            true => println!("Watching {} files. Press Ctrl-C to stop", changed.len()),
+            false => {
+                for path in &changed {
+                    println!("Changed: {}", path.display());
+                }
+            },
+        }
+
+        match build() {
+            Some(executable) => {
+                let targets =
+                    run_targets(&executable, year, day, part, timeout, &mut previous);
+                println!();
+                println!("{}", targets);
+            },
+            None => println!("Build failed, waiting for changes"),
+        }
+        println!();
+    }
+}
+
+/// Module file and directory of the day, its input and example inputs.
+fn watched_files(year: &Year, day: u8) -> Vec<PathBuf> {
+    let days_dir = Path::new(year.sources).join("days");
+    let mut files = vec![days_dir.join(format!("day{:02}.rs", day))];
+    collect_files(&days_dir.join(format!("day{:02}", day)), &mut files);
+
+    files.push(io::input_path(year, day));
+    files.extend(examples(year, day).map(|example| io::example_path(year, day, example)));
+    files
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        match path.is_dir() {
+            true => collect_files(&path, files),
+            false => files.push(path),
+        }
+    }
+}
+
+/// Numbers of the day's example files, counted from 1 until the first missing one.
+fn examples(year: &Year, day: u8) -> impl Iterator<Item = u8> {
+    (1..=u8::MAX)
+        .take_while(move |example| io::example_path(year, day, *example).exists())
+}
+
+fn take_snapshot(files: &[PathBuf]) -> Snapshot {
+    files
+        .iter()
+        .map(|path| {
+            let modified = std::fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok();
+            (path.clone(), modified)
+        })
+        .collect()
+}
+
+/// Files that were added, removed or modified between two snapshots.
+fn changes(before: &Snapshot, after: &Snapshot) -> Vec<PathBuf> {
+    let removed = before.keys().filter(|path| !after.contains_key(*path));
+    let changed = after
+        .iter()
+        .filter(|(path, modified)| before.get(*path) != Some(modified))
+        .map(|(path, _)| path);
+
+    let mut paths = removed.chain(changed).cloned().collect::<Vec<PathBuf>>();
+    paths.sort();
+    paths
+}
+
+/// Rebuilds the runner with the same profile as the running one and returns the path of
+/// the built executable. Compiler output goes straight to the terminal.
+///
+/// The path comes from cargo rather than `current_exe`, which names a deleted file once
+/// cargo replaced the running binary.
+fn build() -> Option<PathBuf> {
+    let mut command = Command::new(std::env::var_os("CARGO").unwrap_or("cargo".into()));
+    command.args(["build", "--quiet", "--bin", "aoc"]);
+    command.args([
+        "--message-format",
+        "json-render-diagnostics",
+        "--manifest-path",
+    ]);
+    command.arg(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"));
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+
+    let output = command.stderr(Stdio::inherit()).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    executable(&output.stdout)
+}
+
+/// Executable of the artifacts reported by `cargo build --message-format json`.
+fn executable(messages: &[u8]) -> Option<PathBuf> {
+    messages
+        .split(|&byte| byte == b'\n')
+        .filter_map(|line| serde_json::from_slice::<Message>(line).ok())
+        .filter(|message| message.reason == "compiler-artifact")
+        .find_map(|message| message.executable)
+}
+
+/// Runs the part on the input and the examples with the freshly built runner. Answers
+/// are compared with the ones of the previous run.
+fn run_targets(
+    executable: &Path, year: &Year, day: u8, part: u8, timeout: Option<Duration>,
+    previous: &mut BTreeMap<Target, Answer>,
+) -> String {
+    const HEADER: [&str; 5] = ["Run", "Answer", "Previous", "Solve", "Status"];
+
+    let targets = std::iter::once(Target::Input)
+        .chain(examples(year, day).map(Target::Example))
+        .collect::<Vec<Target>>();
+
+    let mut rows = Vec::new();
+    for target in targets {
+        let name = match target {
+            Target::Input => "Input".to_string(),
+            Target::Example(example) => format!("Example {}", example),
+        };
+
+        let report = match run(executable, year, day, part, target, timeout) {
+            Ok(report) => report,
+            Err(err) => {
+                rows.push([name, err, "-".to_string(), "-".to_string(), "-".to_string()]);
+                continue;
+            },
+        };
+
+        let answer = report
+            .answer
+            .or(report
+                .error
+                .map(|err| Answer::Text(format!("Error: {}", err))))
+            .unwrap_or(Answer::Text(String::new()));
+        let before = previous.insert(target, answer.clone());
+        rows.push([
+            name,
+            answer.single_line(),
+            compare(before.as_ref(), &answer),
+            format_duration(Duration::from_nanos(report.solve_ns)),
+            report.status,
+        ]);
+    }
+
+    table(HEADER, &rows, &[0, 1, 2, 4]).join("\n")
+}
+
+fn run(
+    executable: &Path, year: &Year, day: u8, part: u8, target: Target,
+    timeout: Option<Duration>,
+) -> Result<Report, String> {
+    let mut command = Command::new(executable);
+    command.args(["--format", "json"]);
+    for (flag, value) in [
+        ("--year", year.number.to_string()),
+        ("--day", day.to_string()),
+        ("--part", part.to_string()),
+    ] {
+        command.args([flag, &value]);
+    }
+    if let Target::Example(example) = target {
+        command.args(["--example", &example.to_string()]);
+    }
+    if let Some(timeout) = timeout {
+        command.args(["--timeout", &timeout.as_secs_f64().to_string()]);
+    }
+    for (name, value) in params::all() {
+        command.args(["--param", &format!("{}={}", name, value)]);
+    }
+
+    let output = command
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|err| err.to_string())?;
+    let reports = serde_json::from_slice::<Vec<Report>>(&output.stdout)
+        .map_err(|_| format!("Runner failed with {}", output.status))?;
+    reports
+        .into_iter()
+        .next()
+        .ok_or("Runner reported nothing".to_string())
+}
+
+/// Previous answer if it differs from the current one.
+fn compare(before: Option<&Answer>, answer: &Answer) -> String {
+    match before {
+        None => "-".to_string(),
+        Some(before) if before == answer => "unchanged".to_string(),
+        Some(before) => before.single_line(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changes() {
+        let time = |seconds| Some(SystemTime::UNIX_EPOCH + Duration::from_secs(seconds));
+        let before = Snapshot::from([
+            (PathBuf::from("a"), time(1)),
+            (PathBuf::from("b"), time(1)),
+            (PathBuf::from("c"), time(1)),
+        ]);
+        let after = Snapshot::from([
+            (PathBuf::from("a"), time(1)),
+            (PathBuf::from("b"), time(2)),
+            (PathBuf::from("d"), None),
+        ]);
+
+        assert_eq!(
+            changes(&before, &after),
+            vec![PathBuf::from("b"), PathBuf::from("c"), PathBuf::from("d")]
+        );
+        assert!(changes(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn test_executable() {
+        let messages = br#"{"reason":"compiler-artifact","executable":null}
+{"reason":"compiler-artifact","executable":"/target/debug/aoc"}
+{"reason":"build-finished","success":true}
+"#;
+
+        assert_eq!(
+            executable(messages),
+            Some(PathBuf::from("/target/debug/aoc"))
+        );
+        assert_eq!(executable(b"{\"reason\":\"build-finished\"}"), None);
+    }
+}
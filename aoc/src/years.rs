@@ -0,0 +1,16 @@
+use common::errors::Error;
+use common::registry::Year;
+
+/// Every year the runner knows about, oldest first.
+pub const YEARS: [&Year; 1] = [&year2025::YEAR];
+
+/// The requested year, or the latest one if none is given.
+pub fn find(year: Option<u16>) -> Result<&'static Year, Error> {
+    match year {
+        Some(number) => YEARS
+            .into_iter()
+            .find(|year| year.number == number)
+            .ok_or(Error::InvalidYear(number)),
+        None => YEARS.last().copied().ok_or(Error::InvalidYear(0)),
+    }
+}
@@ -0,0 +1,408 @@
+use crate::grid::Grid;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Answer of a part. Integers are compared by value, so a recorded `007` matches `7`.
+///
+/// Serialized untagged: integers as JSON numbers, text as a string and a grid as an
+/// array of its rows. Integers beyond 64 bits are written as strings, since JSON readers
+/// like serde_json can't read them back as numbers.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    /// Integers that don't fit into 64 bits, of any size.
+    Big(BigInt),
+    Text(String),
+    /// Multi-line answer, e.g. letters drawn on a grid. One string per row.
+    Grid(Vec<String>),
+}
+
+/// Integer of any size, kept as its sign and decimal digits. Compared by value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BigInt {
+    negative: bool,
+    /// Without leading zeros, `0` for zero, which is never negative.
+    digits: String,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("Invalid integer")]
+pub struct ParseBigIntError;
+
+impl BigInt {
+    /// Non-negative integer from its decimal digits, most significant first.
+    ///
+    /// Panics on digits above 9.
+    pub fn from_digits(digits: &[u8]) -> Self {
+        assert!(
+            digits.iter().all(|&digit| digit < 10),
+            "Decimal digits are below 10"
+        );
+
+        let digits = digits
+            .iter()
+            .map(|&digit| char::from(b'0' + digit))
+            .collect();
+        Self::new(false, digits)
+    }
+
+    fn new(negative: bool, digits: String) -> Self {
+        let digits = digits.trim_start_matches('0');
+        match digits.is_empty() {
+            true => Self {
+                negative: false,
+                digits: "0".to_string(),
+            },
+            false => Self {
+                negative,
+                digits: digits.to_string(),
+            },
+        }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.negative {
+            true => None,
+            false => self.digits.parse().ok(),
+        }
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        self.to_string().parse().ok()
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    /// Accepts an optional sign and decimal digits, like the integer types do.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(ParseBigIntError);
+        }
+        Ok(Self::new(negative, digits.to_string()))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.negative {
+            true => write!(f, "-{}", self.digits),
+            false => write!(f, "{}", self.digits),
+        }
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let magnitude = || {
+            let length = self.digits.len().cmp(&other.digits.len());
+            length.then_with(|| self.digits.cmp(&other.digits))
+        };
+        match (self.negative, other.negative) {
+            (false, false) => magnitude(),
+            (true, true) => magnitude().reverse(),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+macro_rules! big_from_integer {
+    ($($source:ty),+) => {
+        $(
+            impl From<$source> for BigInt {
+                fn from(value: $source) -> Self {
+                    Self::new(value < 0, value.unsigned_abs().to_string())
+                }
+            }
+        )+
+    };
+}
+
+big_from_integer!(i64, i128);
+
+impl From<u64> for BigInt {
+    fn from(value: u64) -> Self {
+        Self::new(false, value.to_string())
+    }
+}
+
+impl From<u128> for BigInt {
+    fn from(value: u128) -> Self {
+        Self::new(false, value.to_string())
+    }
+}
+
+impl Answer {
+    pub fn as_integer(&self) -> Option<BigInt> {
+        match self {
+            Answer::Unsigned(value) => Some(BigInt::from(*value)),
+            Answer::Signed(value) => Some(BigInt::from(*value)),
+            Answer::Big(value) => Some(value.clone()),
+            Answer::Text(_) | Answer::Grid(_) => None,
+        }
+    }
+
+    /// Checks the answer against a recorded one. Integers are parsed and compared by
+    /// value, the rest by text with trailing whitespace of the lines ignored.
+    pub fn matches(&self, expected: &str) -> bool {
+        match self.as_integer() {
+            Some(value) => expected.trim().parse::<BigInt>() == Ok(value),
+            None => {
+                let answer = self.to_string();
+                answer
+                    .lines()
+                    .map(str::trim_end)
+                    .eq(expected.lines().map(str::trim_end))
+            },
+        }
+    }
+
+    /// The answer on a single line, rows of a grid separated by ` / `.
+    pub fn single_line(&self) -> String {
+        match self {
+            Answer::Grid(rows) => rows.join(" / "),
+            answer => answer.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Signed(value) => write!(f, "{}", value),
+            Answer::Big(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+/// How an answer looks in JSON.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum Json<'a> {
+    Unsigned(u64),
+    Signed(i64),
+    Text(std::borrow::Cow<'a, str>),
+    Grid(std::borrow::Cow<'a, [String]>),
+}
+
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let json = match self {
+            Answer::Unsigned(value) => Json::Unsigned(*value),
+            Answer::Signed(value) => Json::Signed(*value),
+            Answer::Big(value) => match (value.to_u64(), value.to_i64()) {
+                (Some(value), _) => Json::Unsigned(value),
+                (_, Some(value)) => Json::Signed(value),
+                _ => Json::Text(value.to_string().into()),
+            },
+            Answer::Text(text) => Json::Text(text.into()),
+            Answer::Grid(rows) => Json::Grid(rows.into()),
+        };
+        json.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match Json::deserialize(deserializer)? {
+            Json::Unsigned(value) => Answer::Unsigned(value),
+            Json::Signed(value) => Answer::Signed(value),
+            // Written by `serialize` for integers beyond 64 bits.
+            Json::Text(text) => match text.parse::<BigInt>() {
+                Ok(value)
+                    if value.to_string() == text
+                        && value.to_u64().is_none()
+                        && value.to_i64().is_none() =>
+                {
+                    Answer::Big(value)
+                },
+                _ => Answer::Text(text.into_owned()),
+            },
+            Json::Grid(rows) => Answer::Grid(rows.into_owned()),
+        })
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.as_integer(), other.as_integer()) {
+            (Some(left), Some(right)) => left == right,
+            (None, None) => self.matches(&other.to_string()),
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        self.matches(other)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self.matches(other)
+    }
+}
+
+impl PartialEq<String> for Answer {
+    fn eq(&self, other: &String) -> bool {
+        self.matches(other)
+    }
+}
+
+macro_rules! from_integer {
+    ($variant:ident($target:ty): $($source:ty),+) => {
+        $(
+            impl From<$source> for Answer {
+                fn from(value: $source) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )+
+    };
+}
+
+from_integer!(Unsigned(u64): u8, u16, u32, u64, usize);
+from_integer!(Signed(i64): i8, i16, i32, i64, isize);
+
+impl From<BigInt> for Answer {
+    fn from(value: BigInt) -> Self {
+        Answer::Big(value)
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        Answer::Big(value.into())
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        Answer::Big(value.into())
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl<T: fmt::Display> From<&Grid<T>> for Answer {
+    fn from(grid: &Grid<T>) -> Self {
+        let rows = grid
+            .iter_rows()
+            .map(|row| row.iter().map(ToString::to_string).collect())
+            .collect();
+        Answer::Grid(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches() {
+        assert!(Answer::from(7u8).matches("007"));
+        assert!(Answer::from(-3).matches(" -3\n"));
+        assert!(Answer::from(i128::MAX).matches(&i128::MAX.to_string()));
+        assert!(!Answer::from(7u8).matches("seven"));
+        assert!(Answer::from("abc").matches("abc"));
+        assert!(!Answer::from("007").matches("7"));
+
+        let grid = Grid::parse("#.\n.#", Ok::<char, ()>).unwrap();
+        assert!(Answer::from(&grid).matches("#.  \n.#"));
+        assert_eq!(Answer::from(&grid).single_line(), "#. / .#");
+    }
+
+    #[test]
+    fn test_typed_equality() {
+        assert_eq!(Answer::from(5u64), Answer::from(5i32));
+        assert_eq!(Answer::from(5u64), Answer::from(5i128));
+        assert_ne!(Answer::from(5u64), Answer::from("5"));
+    }
+
+    #[test]
+    fn test_big_int() {
+        let parse = |text: &str| text.parse::<BigInt>();
+
+        assert_eq!(parse("+007"), Ok(BigInt::from(7u64)));
+        assert_eq!(parse("-0"), Ok(BigInt::from(0u64)));
+        assert_eq!(parse("-12").unwrap().to_string(), "-12");
+        assert_eq!(parse(""), Err(ParseBigIntError));
+        assert_eq!(parse("1e5"), Err(ParseBigIntError));
+        assert_eq!(BigInt::from_digits(&[0, 4, 2]), BigInt::from(42u64));
+
+        let big = parse(&"1".repeat(45)).unwrap();
+        assert!(big > BigInt::from(u128::MAX));
+        assert!(BigInt::from(-5i64) < BigInt::from(-4i64));
+        assert!(BigInt::from(-5i64) < BigInt::from(0u64));
+        assert_eq!(big.to_u64(), None);
+        assert_eq!(BigInt::from(i64::MIN).to_i64(), Some(i64::MIN));
+        assert!(Answer::Big(big.clone()).matches(&format!("0{}", big)));
+    }
+
+    #[test]
+    fn test_json() {
+        let answers = [Answer::from(5u8), Answer::from(-5), Answer::from("x")];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(json, r#"[5,-5,"x"]"#);
+        assert_eq!(serde_json::from_str::<Vec<Answer>>(&json).unwrap(), answers);
+    }
+
+    #[test]
+    fn test_json_big() {
+        let huge = format!("-{}", "9".repeat(50)).parse::<BigInt>().unwrap();
+        let values = [
+            BigInt::from(i128::MAX),
+            BigInt::from(i128::MIN),
+            BigInt::from(u128::MAX),
+            BigInt::from(u64::MAX as u128 + 1),
+            BigInt::from(7i128),
+            huge,
+        ];
+        for value in values {
+            let json = serde_json::to_string(&Answer::from(value.clone())).unwrap();
+            let answer = serde_json::from_str::<Answer>(&json).unwrap();
+            assert_eq!(answer.as_integer(), Some(value));
+        }
+        assert_eq!(serde_json::to_string(&Answer::from(7i128)).unwrap(), "7");
+
+        let text = serde_json::from_str::<Answer>(
+            r#""0170141183460469231731687303715884105727""#,
+        );
+        assert!(matches!(text.unwrap(), Answer::Text(_)));
+    }
+}
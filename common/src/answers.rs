@@ -0,0 +1,189 @@
+use crate::io;
+use crate::registry::Year;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Known correct answers, stored in `answers.txt` next to the inputs.
+///
+/// Each line is `DAY PART ANSWER`. Empty lines and lines starting with `#` are skipped.
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+impl Answers {
+    pub fn load(year: &Year) -> Result<Self, crate::errors::Error> {
+        let answers = match io::read_answers_file(year)? {
+            Some(content) => content.parse()?,
+            None => Self::default(),
+        };
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Appends a confirmed answer to `answers.txt`, unless it's recorded already. A
+    /// different recorded answer is superseded, since later lines win.
+    pub fn record(
+        year: &Year, day: u8, part: u8, answer: &str,
+    ) -> Result<(), crate::errors::Error> {
+        let content = io::read_answers_file(year)?;
+        let answers = match &content {
+            Some(content) => content.parse()?,
+            None => Self::default(),
+        };
+        if answers.get(day, part) == Some(answer) {
+            return Ok(());
+        }
+
+        let prefix = match content.as_deref() {
+            None => "# Day Part Answer\n",
+            Some(content) if !content.is_empty() && !content.ends_with('\n') => "\n",
+            Some(_) => "",
+        };
+        let line = format!("{}{:02} {} {}\n", prefix, day, part, answer);
+        let path = io::answers_path(year);
+        Ok(io::append_file(&path, &line)?)
+    }
+}
+
+impl FromStr for Answers {
+    type Err = AnswersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = BTreeMap::new();
+        for (index, line) in s.lines().enumerate() {
+            if let Some(([day, part], answer)) = parse_line(index, line)? {
+                answers.insert((day, part), answer);
+            }
+        }
+
+        Ok(Self { answers })
+    }
+}
+
+/// Expected answers for the examples, stored in `examples/answers.txt`.
+///
+/// Each line is `DAY EXAMPLE PART ANSWER`.
+#[derive(Debug, Default)]
+pub struct ExampleAnswers {
+    answers: BTreeMap<(u8, u8, u8), String>,
+}
+
+impl ExampleAnswers {
+    pub fn load(year: &Year) -> Result<Self, crate::errors::Error> {
+        let answers = match io::read_example_answers_file(year)? {
+            Some(content) => content.parse()?,
+            None => Self::default(),
+        };
+
+        Ok(answers)
+    }
+
+    /// Answers of a single example, keyed like the puzzle answers.
+    pub fn example(&self, example: u8) -> Answers {
+        let answers = self
+            .answers
+            .iter()
+            .filter(|((_, number, _), _)| *number == example)
+            .map(|((day, _, part), answer)| ((*day, *part), answer.clone()))
+            .collect();
+
+        Answers { answers }
+    }
+
+    /// Iterates over `(day, example, part)` and the expected answer.
+    pub fn iter(&self) -> impl Iterator<Item = ((u8, u8, u8), &str)> {
+        self.answers
+            .iter()
+            .map(|(key, answer)| (*key, answer.as_str()))
+    }
+}
+
+impl FromStr for ExampleAnswers {
+    type Err = AnswersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = BTreeMap::new();
+        for (index, line) in s.lines().enumerate() {
+            if let Some(([day, example, part], answer)) = parse_line(index, line)? {
+                answers.insert((day, example, part), answer);
+            }
+        }
+
+        Ok(Self { answers })
+    }
+}
+
+/// Parses `N` numeric key fields followed by the answer. Returns `None` for skipped lines.
+fn parse_line<const N: usize>(
+    index: usize, line: &str,
+) -> Result<Option<([u8; N], String)>, AnswersError> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return Ok(None);
+    }
+
+    let invalid_line = || AnswersError::InvalidLine(index + 1, line.to_string());
+
+    let mut fields = line.split_whitespace();
+    let mut key = [0; N];
+    for value in key.iter_mut() {
+        *value = fields
+            .next()
+            .and_then(|field| field.parse::<u8>().ok())
+            .ok_or_else(invalid_line)?;
+    }
+    let answer = fields.next().ok_or_else(invalid_line)?;
+    if fields.next().is_some() {
+        return Err(invalid_line());
+    }
+
+    Ok(Some((key, answer.to_string())))
+}
+
+#[derive(Debug, Error)]
+pub enum AnswersError {
+    #[error("Invalid line {0}: '{1}'")]
+    InvalidLine(usize, String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let input = "# Day Part Answer
+01 1 999
+
+10 2 17424";
+
+        let answers = input.parse::<Answers>().unwrap();
+
+        assert_eq!(answers.get(1, 1), Some("999"));
+        assert_eq!(answers.get(10, 2), Some("17424"));
+        assert_eq!(answers.get(1, 2), None);
+    }
+
+    #[test]
+    fn test_parse_examples() {
+        let input = "11 2 2 2";
+
+        let answers = input.parse::<ExampleAnswers>().unwrap();
+
+        assert_eq!(answers.example(2).get(11, 2), Some("2"));
+        assert_eq!(answers.example(1).get(11, 2), None);
+    }
+
+    #[test]
+    fn test_invalid_line() {
+        let result = "01 1".parse::<Answers>();
+
+        assert!(matches!(result, Err(AnswersError::InvalidLine(1, _))));
+    }
+}
@@ -0,0 +1,131 @@
+use std::cell::{Cell, RefCell};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// How many calls to [`check`] share one look at the clock.
+const CHECK_INTERVAL: u32 = 1024;
+
+/// Cooperative cancellation of a running part. It is cancelled explicitly or once its
+/// deadline passes. Solutions don't see the token itself, they call [`check`].
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+/// The running part was cancelled.
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("Cancelled")]
+pub struct Cancelled;
+
+impl CancellationToken {
+    /// Token that is cancelled once `timeout` has elapsed from now.
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self {
+            cancelled: Arc::default(),
+            deadline: Instant::now().checked_add(timeout),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Runs `job` with this token installed for the current thread.
+    pub fn scope<R>(&self, job: impl FnOnce() -> R) -> R {
+        let previous = CURRENT.replace(Some(self.clone()));
+        CALLS.set(0);
+        let result = job();
+        CURRENT.set(previous);
+        result
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+    static CALLS: Cell<u32> = const { Cell::new(0) };
+}
+
+/// Checks the token of the part running on this thread. Cheap enough for hot loops:
+/// the clock is only read every [`CHECK_INTERVAL`] calls. Without a token it never
+/// fails.
+pub fn check() -> Result<(), Cancelled> {
+    let calls = CALLS.get().wrapping_add(1);
+    CALLS.set(calls);
+    if !calls.is_multiple_of(CHECK_INTERVAL) {
+        return Ok(());
+    }
+
+    let cancelled = CURRENT
+        .with_borrow(|token| token.as_ref().is_some_and(CancellationToken::is_cancelled));
+    match cancelled {
+        true => Err(Cancelled),
+        false => Ok(()),
+    }
+}
+
+/// Time left for the part running on this thread, `None` without a deadline. For
+/// solvers that take a timeout of their own, like z3. Unlike [`check`] it always reads
+/// the clock, so call it between larger steps only.
+pub fn remaining() -> Result<Option<Duration>, Cancelled> {
+    CURRENT.with_borrow(|token| match token {
+        Some(token) if token.is_cancelled() => Err(Cancelled),
+        Some(token) => Ok(token
+            .deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))),
+        None => Ok(None),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spin() -> Result<u32, Cancelled> {
+        let mut iterations = 0;
+        loop {
+            check()?;
+            iterations += 1;
+            if iterations == 10 * CHECK_INTERVAL {
+                return Ok(iterations);
+            }
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(spin(), Ok(10 * CHECK_INTERVAL));
+
+        let token = CancellationToken::with_timeout(Duration::from_secs(60));
+        assert_eq!(token.scope(spin), Ok(10 * CHECK_INTERVAL));
+
+        token.cancel();
+        assert_eq!(token.scope(spin), Err(Cancelled));
+        assert!(CancellationToken::with_timeout(Duration::ZERO).is_cancelled());
+
+        // The token is only installed inside the scope.
+        assert_eq!(spin(), Ok(10 * CHECK_INTERVAL));
+    }
+
+    #[test]
+    fn test_remaining() {
+        assert_eq!(remaining(), Ok(None));
+
+        let token = CancellationToken::with_timeout(Duration::from_secs(60));
+        let left = token.scope(remaining).unwrap().unwrap();
+        assert!(left > Duration::from_secs(59) && left <= Duration::from_secs(60));
+        assert_eq!(CancellationToken::default().scope(remaining), Ok(None));
+
+        token.cancel();
+        assert_eq!(token.scope(remaining), Err(Cancelled));
+    }
+}
@@ -0,0 +1,164 @@
+use crate::grid::ParseError;
+use std::fmt;
+use std::ops::Range;
+
+/// Location of a problem in the puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    /// 1-based line number.
+    pub line: usize,
+    /// 0-based character columns within the line.
+    pub columns: Range<usize>,
+    /// The offending line.
+    pub text: String,
+}
+
+impl Span {
+    /// `line_index` is 0-based, `columns` are character columns within `text`.
+    pub fn new(line_index: usize, text: &str, columns: Range<usize>) -> Self {
+        Self {
+            line: line_index + 1,
+            columns,
+            text: text.to_string(),
+        }
+    }
+
+    /// Locates `part`, which has to be a slice of `input` (e.g. a line or a token of it).
+    ///
+    /// Parts spanning several lines are cut at the end of the first one.
+    pub fn locate(input: &str, part: &str) -> Self {
+        let start = (part.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|start| *start + part.len() <= input.len())
+            .unwrap_or(0);
+
+        let line_start = input[..start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let line_end = input[start..]
+            .find('\n')
+            .map(|i| start + i)
+            .unwrap_or(input.len());
+        let end = (start + part.len()).min(line_end);
+
+        let line_index = input[..start].matches('\n').count();
+        let text = input[line_start..line_end].trim_end_matches('\r');
+        let first_column = input[line_start..start].chars().count();
+        let last_column = first_column + input[start..end].chars().count();
+
+        Self::new(line_index, text, first_column..last_column)
+    }
+
+    /// Renders the line with the columns underlined by carets.
+    pub fn render(&self) -> String {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let carets = "^".repeat(self.columns.len().max(1));
+
+        format!(
+            "{} |\n{} | {}\n{} | {}{}",
+            gutter,
+            number,
+            self.text,
+            gutter,
+            " ".repeat(self.columns.start),
+            carets
+        )
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.columns.start + 1)
+    }
+}
+
+/// Parsing error with its location in the input.
+#[derive(Debug)]
+pub struct Diagnostic<E> {
+    pub error: E,
+    pub span: Span,
+}
+
+impl<E> Diagnostic<E> {
+    /// Locates an error of parsing `input` as a grid. Rows of different lengths are
+    /// reported with the `ragged` error.
+    pub fn from_grid(
+        input: &str, error: ParseError<E>, ragged: impl FnOnce() -> E,
+    ) -> Self {
+        let (row, columns, error) = match error {
+            ParseError::Cell { row, column, error } => (row, Some(column), error),
+            ParseError::Ragged { row, .. } => (row, None, ragged()),
+        };
+        let line = input.lines().nth(row).unwrap_or_default();
+        let columns =
+            columns.map_or(0..line.chars().count(), |column| column..column + 1);
+
+        Self {
+            error,
+            span: Span::new(row, line, columns),
+        }
+    }
+
+    /// Moves the span to another line, for parsers that only see a single line.
+    pub fn on_line(mut self, line_index: usize) -> Self {
+        self.span.line = line_index + 1;
+        self
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for Diagnostic<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.error, self.span)
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for Diagnostic<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+pub trait Locate<T, E> {
+    /// Attaches the location to the error.
+    fn at(self, span: impl FnOnce() -> Span) -> Result<T, Diagnostic<E>>;
+}
+
+impl<T, E> Locate<T, E> for Result<T, E> {
+    fn at(self, span: impl FnOnce() -> Span) -> Result<T, Diagnostic<E>> {
+        self.map_err(|error| Diagnostic {
+            error,
+            span: span(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate() {
+        let input = "L68\nR4x\nL5";
+        let line = input.lines().nth(1).unwrap();
+
+        let span = Span::locate(input, &line[1..]);
+
+        assert_eq!(span.line, 2);
+        assert_eq!(span.columns, 1..3);
+        assert_eq!(span.text, "R4x");
+    }
+
+    #[test]
+    fn test_locate_foreign_part() {
+        let span = Span::locate("abc\ndef", "zzz");
+
+        assert_eq!(span.line, 1);
+        assert_eq!(span.columns, 0..3);
+    }
+
+    #[test]
+    fn test_render() {
+        let span = Span::new(9, "R4x", 1..3);
+
+        assert_eq!(span.render(), "   |\n10 | R4x\n   |  ^^");
+    }
+}
@@ -0,0 +1,198 @@
+use crate::diagnostic::{Diagnostic, Span};
+use crate::{answers, cancel, io};
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("IO. {0}")]
+    Io(#[from] io::IoError),
+
+    #[error("Answers. {0}")]
+    Answers(#[from] answers::AnswersError),
+
+    #[error("Invalid baseline {}: {}", .0.display(), .1)]
+    InvalidBaseline(std::path::PathBuf, String),
+
+    #[error("Invalid submission history {}: {}", .0.display(), .1)]
+    InvalidHistory(std::path::PathBuf, String),
+
+    #[error("No session token. Set AOC_SESSION or save it to {}", .0.display())]
+    MissingSession(std::path::PathBuf),
+
+    #[error("Request to {0} failed: {1}")]
+    Http(String, String),
+
+    #[error("Submission refused: {0}")]
+    SubmissionRefused(String),
+
+    /// Unknown command or wrong arguments at the `explore` prompt.
+    #[error("{0}")]
+    InvalidCommand(String),
+
+    #[error("Invalid value of parameter {0}: {1}")]
+    InvalidParam(String, String),
+
+    #[error("Invalid Year: {0}")]
+    InvalidYear(u16),
+
+    #[error("Invalid Day: {0}")]
+    InvalidDay(u8),
+
+    #[error("Invalid Part. It have to be 1 or 2. Value: {0}")]
+    InvalidPart(u8),
+
+    #[error("Wrong answer: {0}. Expected: {1}")]
+    WrongAnswer(String, String),
+
+    #[error("Day {0}. Part {1} is not implemented yet")]
+    NotImplemented(u8, u8),
+
+    #[error("{0}")]
+    Cancelled(#[from] cancel::Cancelled),
+
+    #[error("Timed out after {0:?}")]
+    Timeout(Duration),
+
+    #[error("Day {day:02}. {source}{}", .span.as_ref().map(|span| format!(" ({})", span)).unwrap_or_default())]
+    Day {
+        day: u8,
+        source: Box<dyn std::error::Error + Send + Sync>,
+        span: Option<Span>,
+        category: Category,
+    },
+}
+
+/// Error category. Each has its own process exit code, listed in `--help`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    WrongAnswer,
+    Usage,
+    Io,
+    Parse,
+    Solver,
+    NotImplemented,
+    /// A benchmark is slower than its baseline by more than the threshold.
+    Regression,
+    Timeout,
+    /// The website asks to wait before submitting another answer.
+    RateLimited,
+}
+
+impl Category {
+    pub fn name(self) -> &'static str {
+        match self {
+            Category::WrongAnswer => "wrong_answer",
+            Category::Usage => "usage",
+            Category::Io => "io",
+            Category::Parse => "parse",
+            Category::Solver => "solver",
+            Category::NotImplemented => "not_implemented",
+            Category::Regression => "regression",
+            Category::Timeout => "timeout",
+            Category::RateLimited => "rate_limited",
+        }
+    }
+
+    pub fn exit_code(self) -> i32 {
+        match self {
+            Category::WrongAnswer => 1,
+            Category::Usage => 2,
+            Category::Io => 3,
+            Category::Parse => 4,
+            Category::Solver => 5,
+            Category::NotImplemented => 6,
+            Category::Regression => 7,
+            Category::Timeout => 8,
+            Category::RateLimited => 9,
+        }
+    }
+}
+
+/// Error of a single day. Implementing it makes the error (and its [`Diagnostic`])
+/// convertible into [`Error`].
+pub trait DayError: std::error::Error + Send + Sync + 'static {
+    const DAY: u8;
+
+    /// Days report malformed input by default; solver failures override this.
+    fn category(&self) -> Category {
+        Category::Parse
+    }
+}
+
+impl<E: DayError> From<E> for Error {
+    fn from(error: E) -> Self {
+        Error::Day {
+            day: E::DAY,
+            category: error.category(),
+            source: Box::new(error),
+            span: None,
+        }
+    }
+}
+
+impl<E: DayError> From<Diagnostic<E>> for Error {
+    fn from(diagnostic: Diagnostic<E>) -> Self {
+        Error::Day {
+            day: E::DAY,
+            category: diagnostic.error.category(),
+            source: Box::new(diagnostic.error),
+            span: Some(diagnostic.span),
+        }
+    }
+}
+
+impl Error {
+    /// Name of the error's category, e.g. `parse`, as reported in JSON and CSV output.
+    pub fn kind(&self) -> &'static str {
+        self.category().name()
+    }
+
+    pub fn category(&self) -> Category {
+        match self {
+            Error::Io(_) | Error::Http(_, _) => Category::Io,
+            Error::Answers(_)
+            | Error::InvalidBaseline(_, _)
+            | Error::InvalidHistory(_, _) => Category::Parse,
+            Error::MissingSession(_)
+            | Error::SubmissionRefused(_)
+            | Error::InvalidCommand(_)
+            | Error::InvalidParam(_, _)
+            | Error::InvalidYear(_)
+            | Error::InvalidDay(_)
+            | Error::InvalidPart(_) => Category::Usage,
+            Error::WrongAnswer(_, _) => Category::WrongAnswer,
+            Error::NotImplemented(_, _) => Category::NotImplemented,
+            Error::Cancelled(_) | Error::Timeout(_) => Category::Timeout,
+            Error::Day { category, .. } => *category,
+        }
+    }
+
+    /// Location in the input, if the error came from parsing it.
+    pub fn span(&self) -> Option<&Span> {
+        match self {
+            Error::Day { span, .. } => span.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("Bad line")]
+    struct BadLine;
+
+    impl DayError for BadLine {
+        const DAY: u8 = 1;
+    }
+
+    #[test]
+    fn test_kind() {
+        assert_eq!(Error::from(BadLine).kind(), "parse");
+        assert_eq!(Error::InvalidPart(3).kind(), "usage");
+        assert_eq!(Error::Timeout(Duration::ZERO).kind(), "timeout");
+    }
+}
@@ -0,0 +1,168 @@
+use crate::answer::Answer;
+use crate::errors::Error;
+use crate::solution::Solution;
+use std::marker::PhantomData;
+use thiserror::Error;
+
+/// Command of the `explore` prompt. Queries the parsed model of a day, e.g.
+/// `paths svr out`.
+pub struct Command<M> {
+    pub name: &'static str,
+    /// Arguments, as shown in the help, e.g. `FROM TO`.
+    pub args: &'static str,
+    pub help: &'static str,
+    pub run: fn(&M, &[&str]) -> Result<String, CommandError>,
+}
+
+#[derive(Debug, Error)]
+pub enum CommandError {
+    /// Wrong number of arguments. The prompt shows the usage of the command.
+    #[error("Wrong arguments")]
+    Usage,
+
+    #[error("{0}")]
+    Invalid(String),
+
+    /// The day failed to answer, e.g. its solver did.
+    #[error(transparent)]
+    Failed(#[from] Error),
+}
+
+/// Name, arguments and help of a command.
+#[derive(Debug, Clone, Copy)]
+pub struct Usage {
+    pub name: &'static str,
+    pub args: &'static str,
+    pub help: &'static str,
+}
+
+/// Parsed model of a day with its commands, independent of the model type.
+pub trait Explore {
+    fn commands(&self) -> Vec<Usage>;
+
+    /// Runs the command. `None` if the day has no command with this name.
+    fn run(&self, name: &str, args: &[&str]) -> Option<Result<String, CommandError>>;
+
+    /// Solves a part on the explored model.
+    fn solve(&self, part: u8) -> Result<Answer, Error>;
+}
+
+/// Model of the solution `S`, explored with the commands of `S`.
+pub struct Explorer<S: Solution> {
+    model: S::Model,
+    solution: PhantomData<S>,
+}
+
+impl<S: Solution> Explorer<S> {
+    pub fn new(model: S::Model) -> Self {
+        Self {
+            model,
+            solution: PhantomData,
+        }
+    }
+}
+
+impl<S: Solution> Explore for Explorer<S> {
+    fn commands(&self) -> Vec<Usage> {
+        S::COMMANDS
+            .iter()
+            .map(|command| Usage {
+                name: command.name,
+                args: command.args,
+                help: command.help,
+            })
+            .collect()
+    }
+
+    fn run(&self, name: &str, args: &[&str]) -> Option<Result<String, CommandError>> {
+        S::COMMANDS
+            .iter()
+            .find(|command| command.name == name)
+            .map(|command| (command.run)(&self.model, args))
+    }
+
+    fn solve(&self, part: u8) -> Result<Answer, Error> {
+        match part {
+            1 => S::part1(&self.model),
+            2 => S::part2(&self.model),
+            part => Err(Error::InvalidPart(part)),
+        }
+    }
+}
+
+/// Exactly `N` arguments, or [`CommandError::Usage`].
+pub fn arguments<'a, const N: usize>(
+    args: &[&'a str],
+) -> Result<[&'a str; N], CommandError> {
+    args.try_into().map_err(|_| CommandError::Usage)
+}
+
+/// Parses an argument, naming it in the error.
+pub fn parse_argument<T: std::str::FromStr>(value: &str) -> Result<T, CommandError>
+where
+    T::Err: std::fmt::Display,
+{
+    value.parse().map_err(|err| {
+        CommandError::Invalid(format!("Invalid argument '{}': {}", value, err))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Metadata;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Model = Vec<u64>;
+
+        const METADATA: Metadata = Metadata {
+            year: 2000,
+            day: 1,
+            title: "Sum",
+            date: "01.12.2000",
+            parts: 1,
+        };
+
+        const COMMANDS: &'static [Command<Self::Model>] = &[Command {
+            name: "get",
+            args: "INDEX",
+            help: "Number at the index",
+            run: |numbers, args| {
+                let [index] = arguments(args)?;
+                let index = parse_argument::<usize>(index)?;
+                numbers
+                    .get(index)
+                    .map(u64::to_string)
+                    .ok_or(CommandError::Invalid(format!("No number {}", index)))
+            },
+        }];
+
+        fn parse(input: String) -> Result<Self::Model, Error> {
+            Ok(input.split(',').filter_map(|n| n.parse().ok()).collect())
+        }
+
+        fn part1(numbers: &Self::Model) -> Result<Answer, Error> {
+            Ok(numbers.iter().sum::<u64>().into())
+        }
+    }
+
+    #[test]
+    fn test_explore() {
+        let explorer = Explorer::<Sum>::new(vec![4, 5]);
+
+        assert_eq!(explorer.commands()[0].name, "get");
+        assert_eq!(explorer.run("get", &["1"]).unwrap().unwrap(), "5");
+        assert!(matches!(
+            explorer.run("get", &[]),
+            Some(Err(CommandError::Usage))
+        ));
+        assert!(matches!(
+            explorer.run("get", &["x"]),
+            Some(Err(CommandError::Invalid(_)))
+        ));
+        assert!(explorer.run("set", &[]).is_none());
+        assert_eq!(explorer.solve(1).unwrap(), "9");
+    }
+}
@@ -0,0 +1,280 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use thiserror::Error;
+
+/// `(row, column)` of a cell.
+pub type Position = (usize, usize);
+
+/// Up, right, down, left.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Orthogonal and diagonal directions, clockwise from the top-left.
+pub const ALL_DIRECTIONS: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+];
+
+/// Rectangular grid, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    columns: usize,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParseError<E> {
+    #[error("Invalid cell at row {row}, column {column}: {error}")]
+    Cell { row: usize, column: usize, error: E },
+
+    #[error("Row {row} has {found} cells, expected {expected}")]
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(rows: usize, columns: usize, value: T) -> Self {
+        Self {
+            cells: vec![value; rows * columns],
+            rows,
+            columns,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a char map, one row per line. Columns are counted in chars.
+    pub fn parse<E>(
+        input: &str, mut cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError<E>> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut rows = 0;
+        let mut columns = 0;
+
+        for (row, line) in input.lines().enumerate() {
+            let mut found = 0;
+            for (column, symbol) in line.chars().enumerate() {
+                let value = cell(symbol).map_err(|error| ParseError::Cell {
+                    row,
+                    column,
+                    error,
+                })?;
+                cells.push(value);
+                found += 1;
+            }
+
+            if row == 0 {
+                columns = found;
+            } else if found != columns {
+                return Err(ParseError::Ragged {
+                    row,
+                    expected: columns,
+                    found,
+                });
+            }
+            rows += 1;
+        }
+
+        Ok(Self {
+            cells,
+            rows,
+            columns,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn contains(&self, (row, column): Position) -> bool {
+        row < self.rows && column < self.columns
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.offset(position).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.offset(position).map(|offset| &mut self.cells[offset])
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        let start = row.checked_mul(self.columns).filter(|_| row < self.rows)?;
+        Some(&self.cells[start..start + self.columns])
+    }
+
+    /// Cells of the column from top to bottom. Empty if the column is out of bounds.
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        let cells = match column < self.columns {
+            true => &self.cells[column..],
+            false => &[],
+        };
+        cells.iter().step_by(self.columns.max(1))
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks_exact(self.columns.max(1))
+    }
+
+    /// Cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        let columns = self.columns.max(1);
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(offset, cell)| ((offset / columns, offset % columns), cell))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let columns = self.columns;
+        (0..self.rows).flat_map(move |row| (0..columns).map(move |column| (row, column)))
+    }
+
+    /// Position of the first cell matching the predicate, row by row.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// Moves one step in the direction, `None` if that leaves the grid.
+    pub fn step(
+        &self, (row, column): Position, (dr, dc): (isize, isize),
+    ) -> Option<Position> {
+        let position = (row.checked_add_signed(dr)?, column.checked_add_signed(dc)?);
+        self.contains(position).then_some(position)
+    }
+
+    /// Neighbours in the 4 orthogonal directions that are inside the grid.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// Neighbours in all 8 directions that are inside the grid.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> {
+        ALL_DIRECTIONS
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// Cells row by row, for algorithms working on flat offsets.
+    pub fn as_slice(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    fn offset(&self, position: Position) -> Option<usize> {
+        self.contains(position)
+            .then(|| position.0 * self.columns + position.1)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        match self.offset(position) {
+            Some(offset) => &self.cells[offset],
+            None => panic!(
+                "Position {:?} is out of the {}x{} grid",
+                position, self.rows, self.columns
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        match self.offset(position) {
+            Some(offset) => &mut self.cells[offset],
+            None => panic!(
+                "Position {:?} is out of the {}x{} grid",
+                position, self.rows, self.columns
+            ),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, row) in self.iter_rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError<char>> {
+        Grid::parse(input, |symbol| symbol.to_digit(10).ok_or(symbol))
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n456").unwrap();
+        assert_eq!((grid.rows(), grid.columns()), (2, 3));
+        assert_eq!(grid[(1, 2)], 6);
+        assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(grid.column(1).collect::<Vec<_>>(), vec![&2, &5]);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+
+        assert_eq!(
+            digits("12\n3x"),
+            Err(ParseError::Cell {
+                row: 1,
+                column: 1,
+                error: 'x'
+            })
+        );
+        assert_eq!(
+            digits("12\n345"),
+            Err(ParseError::Ragged {
+                row: 1,
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours4((2, 2)).collect::<Vec<_>>(),
+            vec![(1, 2), (2, 1)]
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use crate::registry::Year;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Environment variable that overrides the directory with puzzle inputs of the year.
+pub const INPUT_DIR_VARIABLE: &str = "AOC_INPUT_DIR";
+
+/// Value of `--input` that reads the input from stdin.
+pub const STDIN: &str = "-";
+
+pub fn input_dir(year: &Year) -> PathBuf {
+    std::env::var_os(INPUT_DIR_VARIABLE)
+        .map(PathBuf::from)
+        .unwrap_or(PathBuf::from(year.inputs))
+}
+
+pub fn input_path(year: &Year, day: u8) -> PathBuf {
+    input_dir(year).join(format!("day{:02}.txt", day))
+}
+
+/// Reads the input from `path` (or stdin for `-`), falling back to the day's input file.
+pub fn read_input(year: &Year, day: u8, path: Option<&Path>) -> Result<String, IoError> {
+    match path {
+        Some(path) if path == Path::new(STDIN) => read_stdin(),
+        Some(path) => read_file(path),
+        None => read_input_file(year, day),
+    }
+}
+
+pub fn example_path(year: &Year, day: u8, example: u8) -> PathBuf {
+    input_dir(year)
+        .join("examples")
+        .join(format!("day{:02}-{}.txt", day, example))
+}
+
+pub fn read_input_file(year: &Year, day: u8) -> Result<String, IoError> {
+    read_file(&input_path(year, day))
+}
+
+pub fn read_example_file(year: &Year, day: u8, example: u8) -> Result<String, IoError> {
+    read_file(&example_path(year, day, example))
+}
+
+pub fn answers_path(year: &Year) -> PathBuf {
+    input_dir(year).join("answers.txt")
+}
+
+/// Returns `None` if no answers were recorded yet.
+pub fn read_answers_file(year: &Year) -> Result<Option<String>, IoError> {
+    read_optional_file(&answers_path(year))
+}
+
+/// Returns `None` if no example answers were recorded yet.
+pub fn read_example_answers_file(year: &Year) -> Result<Option<String>, IoError> {
+    read_optional_file(&input_dir(year).join("examples").join("answers.txt"))
+}
+
+fn read_optional_file(path: &Path) -> Result<Option<String>, IoError> {
+    match read_file(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(IoError::NotFound(_)) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+pub fn read_file(path: &Path) -> Result<String, IoError> {
+    let bytes = std::fs::read(path).map_err(|err| IoError::new(path, err))?;
+
+    String::from_utf8(bytes)
+        .map_err(|err| IoError::InvalidUtf8(path.to_path_buf(), err.utf8_error()))
+}
+
+/// Writes the file, creating missing parent directories.
+pub fn write_file(path: &Path, content: &str) -> Result<(), IoError> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|err| IoError::new(parent, err))?;
+    }
+
+    std::fs::write(path, content).map_err(|err| IoError::new(path, err))
+}
+
+/// Appends to the file, creating it and missing parent directories.
+pub fn append_file(path: &Path, content: &str) -> Result<(), IoError> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|err| IoError::new(parent, err))?;
+    }
+
+    std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .map_err(|err| IoError::new(path, err))
+}
+
+/// Like [`write_file`], but fails if the file already exists.
+pub fn write_new_file(path: &Path, content: &str) -> Result<(), IoError> {
+    if path.exists() {
+        return Err(IoError::AlreadyExists(path.to_path_buf()));
+    }
+
+    write_file(path, content)
+}
+
+fn read_stdin() -> Result<String, IoError> {
+    let path = Path::new("<stdin>");
+
+    let mut bytes = Vec::new();
+    std::io::stdin()
+        .read_to_end(&mut bytes)
+        .map_err(|err| IoError::new(path, err))?;
+
+    String::from_utf8(bytes)
+        .map_err(|err| IoError::InvalidUtf8(path.to_path_buf(), err.utf8_error()))
+}
+
+#[derive(Debug, Error)]
+pub enum IoError {
+    #[error("File not found: {}", .0.display())]
+    NotFound(PathBuf),
+
+    #[error("File already exists: {}", .0.display())]
+    AlreadyExists(PathBuf),
+
+    #[error("Permission denied: {}", .0.display())]
+    PermissionDenied(PathBuf),
+
+    #[error("File is not valid UTF-8: {}. {}", .0.display(), .1)]
+    InvalidUtf8(PathBuf, std::str::Utf8Error),
+
+    #[error("Failed to access {}: {}", .0.display(), .1)]
+    Other(PathBuf, std::io::Error),
+}
+
+impl IoError {
+    fn new(path: &Path, error: std::io::Error) -> Self {
+        let path = path.to_path_buf();
+
+        match error.kind() {
+            std::io::ErrorKind::NotFound => Self::NotFound(path),
+            std::io::ErrorKind::AlreadyExists => Self::AlreadyExists(path),
+            std::io::ErrorKind::PermissionDenied => Self::PermissionDenied(path),
+            _ => Self::Other(path, error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_not_found() {
+        let path = Path::new("./inputs/missing.txt");
+
+        let result = read_file(path);
+
+        assert!(matches!(result, Err(IoError::NotFound(tried)) if tried == path));
+    }
+
+    #[test]
+    fn test_invalid_utf8() {
+        let path = std::env::temp_dir().join("aoc_io_invalid_utf8.txt");
+        std::fs::write(&path, [0x66, 0x6f, 0xff]).unwrap();
+
+        let result = read_file(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(result, Err(IoError::InvalidUtf8(tried, _)) if tried == path));
+    }
+}
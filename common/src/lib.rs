@@ -0,0 +1,14 @@
+//! Helpers shared between the years: puzzle registry, input handling, errors and
+//! reusable algorithms.
+
+pub mod answer;
+pub mod answers;
+pub mod cancel;
+pub mod diagnostic;
+pub mod errors;
+pub mod explore;
+pub mod grid;
+pub mod io;
+pub mod params;
+pub mod registry;
+pub mod solution;
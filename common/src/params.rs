@@ -0,0 +1,57 @@
+use crate::errors::Error;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// Values given with `--param NAME=VALUE`, set once at startup.
+static PARAMS: OnceLock<BTreeMap<String, String>> = OnceLock::new();
+
+/// Sets the parameters of this run. Only the first call has an effect.
+pub fn set(params: BTreeMap<String, String>) {
+    let _ = PARAMS.set(params);
+}
+
+/// Every parameter of this run, e.g. to pass them on to a child process.
+pub fn all() -> impl Iterator<Item = (&'static str, &'static str)> {
+    PARAMS
+        .get()
+        .into_iter()
+        .flatten()
+        .map(|(name, value)| (name.as_str(), value.as_str()))
+}
+
+/// Value of the parameter, `None` if it wasn't given. Parts fall back to the puzzle's
+/// value then.
+pub fn get<T: FromStr>(name: &str) -> Result<Option<T>, Error> {
+    lookup(PARAMS.get(), name)
+}
+
+fn lookup<T: FromStr>(
+    params: Option<&BTreeMap<String, String>>, name: &str,
+) -> Result<Option<T>, Error> {
+    let Some(value) = params.and_then(|params| params.get(name)) else {
+        return Ok(None);
+    };
+    value
+        .parse()
+        .map(Some)
+        .map_err(|_| Error::InvalidParam(name.to_string(), value.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        let params = BTreeMap::from([("k".to_string(), "20".to_string())]);
+
+        assert_eq!(lookup::<usize>(Some(&params), "k").unwrap(), Some(20));
+        assert_eq!(lookup::<usize>(Some(&params), "n").unwrap(), None);
+        assert_eq!(lookup::<usize>(None, "k").unwrap(), None);
+        assert!(matches!(
+            lookup::<u8>(Some(&BTreeMap::from([("k".into(), "-1".into())])), "k"),
+            Err(Error::InvalidParam(name, value)) if name == "k" && value == "-1"
+        ));
+    }
+}
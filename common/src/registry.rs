@@ -0,0 +1,207 @@
+use crate::answer::Answer;
+use crate::cancel::CancellationToken;
+use crate::errors::Error;
+use crate::explore::{Explore, Explorer};
+use crate::solution::{Metadata, Solution};
+use std::ops::RangeInclusive;
+use std::time::{Duration, Instant};
+
+/// A year of puzzles. Every year crate exposes one, its days register themselves.
+#[derive(Debug)]
+pub struct Year {
+    pub number: u16,
+    /// Days of the Advent calendar.
+    pub calendar: RangeInclusive<u8>,
+    /// Default directory with the puzzle inputs, overridable with `AOC_INPUT_DIR`.
+    pub inputs: &'static str,
+    /// Source directory of the year crate, where new days are generated.
+    pub sources: &'static str,
+}
+
+impl Year {
+    /// Registered days of this year, ordered by day number.
+    pub fn days(&self) -> Vec<&'static Day> {
+        let mut days = inventory::iter::<Day>
+            .into_iter()
+            .filter(|day| day.metadata.year == self.number)
+            .collect::<Vec<&Day>>();
+        days.sort_by_key(|day| day.metadata.day);
+        days
+    }
+
+    pub fn find(&self, day: u8, part: u8) -> Result<&'static Day, Error> {
+        if !self.calendar.contains(&day) {
+            return Err(Error::InvalidDay(day));
+        }
+
+        self.days()
+            .into_iter()
+            .find(|entry| entry.metadata.day == day)
+            .ok_or(Error::NotImplemented(day, part))
+    }
+
+    pub fn list(&self) -> String {
+        let days = self.days();
+
+        let mut lines = Vec::new();
+        for day in self.calendar.clone() {
+            let line = match days.iter().find(|entry| entry.metadata.day == day) {
+                Some(entry) => format!(
+                    "Day {:02}. {} ({}). Parts: {}/2",
+                    day, entry.metadata.title, entry.metadata.date, entry.metadata.parts
+                ),
+                None => format!("Day {:02}. Not implemented", day),
+            };
+            lines.push(line);
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Registry entry. Every day module submits one with `inventory::submit!`.
+pub struct Day {
+    pub metadata: Metadata,
+    execute: fn(String, &[u8], Option<Duration>) -> Execution,
+    explore: fn(String) -> Result<Box<dyn Explore>, Error>,
+}
+
+inventory::collect!(Day);
+
+impl Day {
+    pub const fn new<S: Solution + 'static>() -> Self {
+        Self {
+            metadata: S::METADATA,
+            execute: execute::<S>,
+            explore: explore::<S>,
+        }
+    }
+
+    pub fn run(
+        &self, input: String, part: u8, timeout: Option<Duration>,
+    ) -> Result<Answer, Error> {
+        if part > self.metadata.parts {
+            return Err(Error::NotImplemented(self.metadata.day, part));
+        }
+
+        let execution = self.execute(input, &[part], timeout);
+        execution
+            .outcome?
+            .into_iter()
+            .next()
+            .ok_or(Error::InvalidPart(part))?
+            .answer
+    }
+
+    /// Parses the input once and solves the given parts, timing both steps separately.
+    /// A part running longer than `timeout` fails with [`Error::Timeout`].
+    pub fn execute(
+        &self, input: String, parts: &[u8], timeout: Option<Duration>,
+    ) -> Execution {
+        (self.execute)(input, parts, timeout)
+    }
+
+    /// Parses the input into a model that can be queried with the day's commands.
+    pub fn explore(&self, input: String) -> Result<Box<dyn Explore>, Error> {
+        (self.explore)(input)
+    }
+}
+
+/// Result of parsing a day's input once and solving some of its parts.
+pub struct Execution {
+    pub day: u8,
+    pub parse_time: Duration,
+    /// Error if the input could not be read or parsed.
+    pub outcome: Result<Vec<PartRun>, Error>,
+}
+
+pub struct PartRun {
+    pub part: u8,
+    pub answer: Result<Answer, Error>,
+    pub solve_time: Duration,
+}
+
+impl Execution {
+    pub fn failed(day: u8, parse_time: Duration, error: Error) -> Self {
+        Self {
+            day,
+            parse_time,
+            outcome: Err(error),
+        }
+    }
+
+    pub fn total_time(&self) -> Duration {
+        let solve_time = match &self.outcome {
+            Ok(runs) => runs.iter().map(|run| run.solve_time).sum(),
+            Err(_) => Duration::ZERO,
+        };
+
+        self.parse_time + solve_time
+    }
+}
+
+fn explore<S: Solution + 'static>(input: String) -> Result<Box<dyn Explore>, Error> {
+    Ok(Box::new(Explorer::<S>::new(S::parse(input)?)))
+}
+
+fn execute<S: Solution>(
+    input: String, parts: &[u8], timeout: Option<Duration>,
+) -> Execution {
+    let start = Instant::now();
+    let model = S::parse(input);
+    let parse_time = start.elapsed();
+
+    let model = match model {
+        Ok(model) => model,
+        Err(err) => return Execution::failed(S::METADATA.day, parse_time, err),
+    };
+
+    let mut runs = Vec::with_capacity(parts.len());
+    for &part in parts {
+        let token = timeout
+            .map(CancellationToken::with_timeout)
+            .unwrap_or_default();
+        let start = Instant::now();
+        let answer = token.scope(|| match part {
+            1 => S::part1(&model),
+            2 => S::part2(&model),
+            part => Err(Error::InvalidPart(part)),
+        });
+        let solve_time = start.elapsed();
+
+        // Parts that don't check the token still finish, but past the deadline.
+        let answer = match (timeout, token.is_cancelled()) {
+            (Some(timeout), true) => Err(Error::Timeout(timeout)),
+            _ => answer,
+        };
+
+        runs.push(PartRun {
+            part,
+            answer,
+            solve_time,
+        });
+    }
+
+    Execution {
+        day: S::METADATA.day,
+        parse_time,
+        outcome: Ok(runs),
+    }
+}
+
+/// Runs a single part. If the expected answer is known, a different answer is an error.
+pub fn run(
+    input: String, day: &Day, part: u8, expected: Option<&str>, timeout: Option<Duration>,
+) -> Result<String, Error> {
+    let output = day.run(input, part, timeout)?;
+    if let Some(expected) = expected
+        && !output.matches(expected)
+    {
+        return Err(Error::WrongAnswer(output.to_string(), expected.to_string()));
+    }
+
+    let header = format!("--- Day {}. Part {} ---", day.metadata.day, part);
+    let formatted_output = format!("{}\n\n{}", header, output);
+
+    Ok(formatted_output)
+}
@@ -0,0 +1,34 @@
+use crate::answer::Answer;
+use crate::errors::Error;
+use crate::explore::Command;
+
+/// Static information about a day, used by the registry and the runner.
+#[derive(Debug, Clone, Copy)]
+pub struct Metadata {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub date: &'static str,
+    /// How many parts are solved. Parts above this number are reported as missing.
+    pub parts: u8,
+}
+
+/// Solution for a single day.
+///
+/// The input is parsed once into [`Solution::Model`], which is then shared by both parts.
+pub trait Solution {
+    type Model: 'static;
+
+    const METADATA: Metadata;
+
+    /// Commands of the `explore` prompt for querying the model. None by default.
+    const COMMANDS: &'static [Command<Self::Model>] = &[];
+
+    fn parse(input: String) -> Result<Self::Model, Error>;
+
+    fn part1(model: &Self::Model) -> Result<Answer, Error>;
+
+    fn part2(_model: &Self::Model) -> Result<Answer, Error> {
+        Err(Error::NotImplemented(Self::METADATA.day, 2))
+    }
+}